@@ -1,4 +1,8 @@
+mod circuit;
 mod elliptic_curve;
-mod field;
+pub(crate) mod field;
 mod kzg;
 mod polynomial;
+mod prover;
+mod transcript;
+mod verifier;
@@ -0,0 +1,629 @@
+use super::field::FieldElement;
+use super::polynomial::Polynomial;
+use ark_bn254::Fr;
+use ark_ff::{BigInteger, FftField};
+use ark_poly::{EvaluationDomain, Radix2EvaluationDomain};
+
+/*
+    PLONK arithmetization: every row i of the circuit is a gate with selectors
+    q_M, q_L, q_R, q_O, q_C and wires a_i, b_i, c_i, satisfied when
+
+        q_M·a·b + q_L·a + q_R·b + q_O·c + q_C + q_range·b·(b - 1) = 0
+
+    Rows are padded with empty gates up to n = 2^k so that each column can be
+    interpolated over the roots of unity H = {ω^0, ..., ω^(n-1)}. The gate
+    identity then holds on the whole of H, i.e. it's divisible by x^n - 1.
+
+    q_range is a custom gate: with q_range = 1 and every other selector zero,
+    a row only holds if b is 0 or 1. Range checks decompose a value into such
+    boolean rows and recompose it with ordinary add gates. The boolean rows
+    don't do any arithmetic, otherwise b·(b - 1) could cancel against the
+    other terms.
+
+    Public inputs x_i take the first rows, as gates a_i - x_i = 0 (q_L = 1).
+    The x_i aren't baked into the selectors: they enter through
+    PI(x), which equals -x_i at ω^i, so the identity becomes
+
+        q_M·a·b + q_L·a + q_R·b + q_O·c + q_C + q_range·b·(b - 1) + PI = 0
+
+    Copy constraints are a permutation σ of the 3n wire positions, position
+    j·n + i being column j (a, b, c) of row i. Position j·n + i is labelled
+    k_j·ω^i, with k_0 = 1, k_1 = g, k_2 = g^2 for the multiplicative generator
+    g of Fr. These land in three disjoint cosets of H, so every label is distinct.
+
+    Wires declared equal with `connect` form classes (a union-find over the
+    positions), and σ cycles through each class. Positions nobody connected
+    are fixed points of σ.
+*/
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Gate {
+    pub q_m: FieldElement,
+    pub q_l: FieldElement,
+    pub q_r: FieldElement,
+    pub q_o: FieldElement,
+    pub q_c: FieldElement,
+    pub q_range: FieldElement,
+}
+
+impl Gate {
+    /// Every selector zero, satisfied by any assignment (used as padding)
+    #[allow(dead_code)]
+    pub fn empty() -> Self {
+        Gate {
+            q_m: FieldElement::zero(),
+            q_l: FieldElement::zero(),
+            q_r: FieldElement::zero(),
+            q_o: FieldElement::zero(),
+            q_c: FieldElement::zero(),
+            q_range: FieldElement::zero(),
+        }
+    }
+
+    /// a + b = c
+    #[allow(dead_code)]
+    pub fn add() -> Self {
+        Gate {
+            q_l: FieldElement::one(),
+            q_r: FieldElement::one(),
+            q_o: FieldElement::one().negate(),
+            ..Gate::empty()
+        }
+    }
+
+    /// a·b = c
+    #[allow(dead_code)]
+    pub fn mul() -> Self {
+        Gate {
+            q_m: FieldElement::one(),
+            q_o: FieldElement::one().negate(),
+            ..Gate::empty()
+        }
+    }
+
+    /// b ∈ {0, 1}
+    #[allow(dead_code)]
+    pub fn boolean() -> Self {
+        Gate {
+            q_range: FieldElement::one(),
+            ..Gate::empty()
+        }
+    }
+
+    /// q_M·a·b + q_L·a + q_R·b + q_O·c + q_C + q_range·b·(b - 1)
+    #[allow(dead_code)]
+    pub fn evaluate(&self, a: FieldElement, b: FieldElement, c: FieldElement) -> FieldElement {
+        self.q_m * a * b
+            + self.q_l * a
+            + self.q_r * b
+            + self.q_o * c
+            + self.q_c
+            + self.q_range * b * (b - FieldElement::one())
+    }
+}
+
+/// One of the three wire columns
+#[allow(dead_code)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Column {
+    A,
+    B,
+    C,
+}
+
+/// The wire in `column` of `row`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WireRef {
+    pub column: Column,
+    pub row: usize,
+}
+
+impl WireRef {
+    #[allow(dead_code)]
+    pub fn new(column: Column, row: usize) -> Self {
+        WireRef { column, row }
+    }
+
+    /// j·n + i for column j and row i in a circuit of size n
+    #[allow(dead_code)]
+    pub fn position(&self, n: usize) -> usize {
+        let j = match self.column {
+            Column::A => 0,
+            Column::B => 1,
+            Column::C => 2,
+        };
+        j * n + self.row
+    }
+}
+
+/// Wire values of every row, the prover's secret assignment
+#[derive(Clone, Debug, PartialEq)]
+pub struct Witness {
+    pub a: Vec<FieldElement>,
+    pub b: Vec<FieldElement>,
+    pub c: Vec<FieldElement>,
+}
+
+/// Gates together with the wire assignment of each row
+#[derive(Clone, Debug, Default)]
+pub struct Circuit {
+    pub gates: Vec<Gate>,
+    pub a: Vec<FieldElement>,             // left wires
+    pub b: Vec<FieldElement>,             // right wires
+    pub c: Vec<FieldElement>,             // output wires
+    pub public_inputs: Vec<FieldElement>, // values of the first rows' a wires
+    pub copies: Vec<(WireRef, WireRef)>,  // pairs of wires declared equal
+}
+
+impl Circuit {
+    #[allow(dead_code)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a gate with its wire values, returns its row
+    #[allow(dead_code)]
+    pub fn add_gate(
+        &mut self,
+        gate: Gate,
+        a: FieldElement,
+        b: FieldElement,
+        c: FieldElement,
+    ) -> usize {
+        self.gates.push(gate);
+        self.a.push(a);
+        self.b.push(b);
+        self.c.push(c);
+        self.gates.len() - 1
+    }
+
+    /// Appends a public input row, returns its row. Public inputs must be
+    /// added before any other gate so that they take the first rows.
+    #[allow(dead_code)]
+    pub fn add_public_input(&mut self, value: FieldElement) -> usize {
+        assert_eq!(
+            self.gates.len(),
+            self.public_inputs.len(),
+            "Public inputs must come before the gates"
+        );
+        self.public_inputs.push(value);
+        let gate = Gate {
+            q_l: FieldElement::one(),
+            ..Gate::empty()
+        };
+        self.add_gate(gate, value, FieldElement::zero(), FieldElement::zero())
+    }
+
+    /// Declares that wires `a` and `b` carry the same value. The classes of
+    /// connected wires are compiled into σ by `permutation`.
+    #[allow(dead_code)]
+    pub fn connect(&mut self, a: WireRef, b: WireRef) {
+        assert!(
+            a.row < self.gates.len() && b.row < self.gates.len(),
+            "Wire refers to a row that doesn't exist"
+        );
+        self.copies.push((a, b));
+    }
+
+    /// Range check of `value` to `bits` bits. Adds a boolean row per bit (most
+    /// significant first) and add gates accumulating acc_i = 2·acc_(i-1) + bit_i,
+    /// wired together. Returns the wire holding the recomposed value, to be
+    /// connected wherever `value` is used: if it doesn't fit in `bits` bits,
+    /// only its low bits are recomposed and that copy constraint fails.
+    #[allow(dead_code)]
+    pub fn add_range_gate(&mut self, value: FieldElement, bits: usize) -> WireRef {
+        assert!(bits > 0, "A range check needs at least one bit");
+        let zero = FieldElement::zero();
+        let value = value.into_bigint();
+        let bit_values: Vec<FieldElement> = (0..bits)
+            .rev()
+            .map(|i| FieldElement::new(value.get_bit(i) as u64))
+            .collect();
+
+        let bit_rows: Vec<usize> = bit_values
+            .iter()
+            .map(|&bit| self.add_gate(Gate::boolean(), zero, bit, zero))
+            .collect();
+
+        // acc_i = 2·acc_(i-1) + bit_i
+        let accumulate = Gate {
+            q_l: FieldElement::new(2),
+            q_r: FieldElement::one(),
+            q_o: FieldElement::one().negate(),
+            ..Gate::empty()
+        };
+        let mut acc = WireRef::new(Column::B, bit_rows[0]);
+        let mut acc_value = bit_values[0];
+        for (&bit, &bit_row) in bit_values.iter().zip(&bit_rows).skip(1) {
+            let next = FieldElement::new(2) * acc_value + bit;
+            let row = self.add_gate(accumulate, acc_value, bit, next);
+            self.connect(acc, WireRef::new(Column::A, row));
+            self.connect(
+                WireRef::new(Column::B, bit_row),
+                WireRef::new(Column::B, row),
+            );
+            acc = WireRef::new(Column::C, row);
+            acc_value = next;
+        }
+        acc
+    }
+
+    /// The current wire assignment
+    #[allow(dead_code)]
+    pub fn witness(&self) -> Witness {
+        Witness {
+            a: self.a.clone(),
+            b: self.b.clone(),
+            c: self.c.clone(),
+        }
+    }
+
+    /// Same gates with another assignment, one value per row in each column
+    #[allow(dead_code)]
+    pub fn with_witness(&self, witness: &Witness) -> Circuit {
+        let rows = self.gates.len();
+        assert!(
+            witness.a.len() == rows && witness.b.len() == rows && witness.c.len() == rows,
+            "Witness must have a value per row in each column"
+        );
+        Circuit {
+            a: witness.a.clone(),
+            b: witness.b.clone(),
+            c: witness.c.clone(),
+            ..self.clone()
+        }
+    }
+
+    /// Number of rows after padding: the smallest power of two holding every gate
+    #[allow(dead_code)]
+    pub fn size(&self) -> usize {
+        self.gates.len().max(1).next_power_of_two()
+    }
+
+    /// H = {ω^0, ..., ω^(n-1)}
+    #[allow(dead_code)]
+    pub fn domain(&self) -> Radix2EvaluationDomain<Fr> {
+        Radix2EvaluationDomain::new(self.size()).expect("BN254 Fr has 2^28 roots of unity")
+    }
+
+    /// Polynomial taking values[i] at ω^i, missing rows padded with zeros
+    #[allow(dead_code)]
+    pub fn interpolate(&self, values: &[FieldElement]) -> Polynomial {
+        let mut evals: Vec<Fr> = values.iter().map(|v| v.value()).collect();
+        evals.resize(self.size(), Fr::from(0u64));
+        let coeffs = self.domain().ifft(&evals);
+        Polynomial::new(coeffs.into_iter().map(FieldElement::from_fr).collect())
+    }
+
+    /// [q_M, q_L, q_R, q_O, q_C, q_range] interpolated over the domain
+    #[allow(dead_code)]
+    pub fn selector_polynomials(&self) -> [Polynomial; 6] {
+        let column = |selector: fn(&Gate) -> FieldElement| {
+            let values: Vec<FieldElement> = self.gates.iter().map(selector).collect();
+            self.interpolate(&values)
+        };
+        [
+            column(|g| g.q_m),
+            column(|g| g.q_l),
+            column(|g| g.q_r),
+            column(|g| g.q_o),
+            column(|g| g.q_c),
+            column(|g| g.q_range),
+        ]
+    }
+
+    /// [a, b, c] interpolated over the domain
+    #[allow(dead_code)]
+    pub fn wire_polynomials(&self) -> [Polynomial; 3] {
+        [
+            self.interpolate(&self.a),
+            self.interpolate(&self.b),
+            self.interpolate(&self.c),
+        ]
+    }
+
+    /// PI(x): -public[i] at ω^i for the first rows, zero on the rest of the domain
+    #[allow(dead_code)]
+    pub fn public_input_polynomial(&self, public: &[FieldElement]) -> Polynomial {
+        let negated: Vec<FieldElement> = public.iter().map(|x| x.negate()).collect();
+        self.interpolate(&negated)
+    }
+
+    /// [k_0, k_1, k_2] = [1, g, g^2], one coset shift per wire column
+    #[allow(dead_code)]
+    pub fn coset_shifts() -> [FieldElement; 3] {
+        let g = FieldElement::from_fr(Fr::GENERATOR);
+        [FieldElement::one(), g, g.multiply(&g)]
+    }
+
+    /// k_j·ω^i, the label of wire position j·n + i
+    #[allow(dead_code)]
+    pub fn wire_label(&self, position: usize) -> FieldElement {
+        let n = self.size();
+        let k = Self::coset_shifts()[position / n];
+        k.multiply(&FieldElement::from_fr(self.domain().element(position % n)))
+    }
+
+    /// σ over the 3n wire positions: each class of connected wires is one
+    /// cycle, in increasing order of position. Without copy constraints it's
+    /// the identity.
+    #[allow(dead_code)]
+    pub fn permutation(&self) -> Vec<usize> {
+        let n = self.size();
+        let mut parent: Vec<usize> = (0..3 * n).collect();
+        for (a, b) in &self.copies {
+            let root_a = find(&mut parent, a.position(n));
+            let root_b = find(&mut parent, b.position(n));
+            parent[root_a] = root_b;
+        }
+
+        // Positions are visited in order, so each class comes out sorted
+        let mut classes: Vec<Vec<usize>> = vec![vec![]; 3 * n];
+        for position in 0..3 * n {
+            let root = find(&mut parent, position);
+            classes[root].push(position);
+        }
+
+        let mut sigma: Vec<usize> = (0..3 * n).collect();
+        for class in classes.iter().filter(|class| class.len() > 1) {
+            for (k, &position) in class.iter().enumerate() {
+                sigma[position] = class[(k + 1) % class.len()];
+            }
+        }
+        sigma
+    }
+
+    /// [S_σ1, S_σ2, S_σ3] with S_σj(ω^i) = label of σ(j·n + i)
+    #[allow(dead_code)]
+    pub fn sigma_polynomials(&self) -> [Polynomial; 3] {
+        let n = self.size();
+        let labels: Vec<FieldElement> = self
+            .permutation()
+            .into_iter()
+            .map(|position| self.wire_label(position))
+            .collect();
+        [
+            self.interpolate(&labels[..n]),
+            self.interpolate(&labels[n..2 * n]),
+            self.interpolate(&labels[2 * n..]),
+        ]
+    }
+
+    /// q_M(x)a(x)b(x) + q_L(x)a(x) + q_R(x)b(x) + q_O(x)c(x) + q_C(x) +
+    /// q_range(x)b(x)(b(x) - 1) + PI(x).
+    /// Vanishes on the whole domain iff every row is satisfied.
+    #[allow(dead_code)]
+    pub fn gate_constraint_polynomial(&self) -> Polynomial {
+        let [q_m, q_l, q_r, q_o, q_c, q_range] = self.selector_polynomials();
+        let [a, b, c] = self.wire_polynomials();
+        let pi = self.public_input_polynomial(&self.public_inputs);
+        let b_minus_one = b.clone() - Polynomial::new(vec![FieldElement::one()]);
+        q_m * a.clone() * b.clone()
+            + q_l * a
+            + q_r * b.clone()
+            + q_o * c
+            + q_c
+            + q_range * b * b_minus_one
+            + pi
+    }
+}
+
+/// Root of the union-find class of `x`, halving paths on the way
+fn find(parent: &mut [usize], mut x: usize) -> usize {
+    while parent[x] != x {
+        parent[x] = parent[parent[x]];
+        x = parent[x];
+    }
+    x
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 1 + 2 = 3, 3 + 4 = 7, 7 + 7 = 14
+    fn adder_circuit() -> Circuit {
+        let mut circuit = Circuit::new();
+        let add = Gate::add();
+        circuit.add_gate(
+            add,
+            FieldElement::new(1),
+            FieldElement::new(2),
+            FieldElement::new(3),
+        );
+        circuit.add_gate(
+            add,
+            FieldElement::new(3),
+            FieldElement::new(4),
+            FieldElement::new(7),
+        );
+        circuit.add_gate(
+            add,
+            FieldElement::new(7),
+            FieldElement::new(7),
+            FieldElement::new(14),
+        );
+        circuit
+    }
+
+    #[test]
+    fn test_gate_evaluate() {
+        let (two, three, six) = (
+            FieldElement::new(2),
+            FieldElement::new(3),
+            FieldElement::new(6),
+        );
+        assert_eq!(Gate::mul().evaluate(two, three, six), FieldElement::zero());
+        assert_ne!(Gate::add().evaluate(two, three, six), FieldElement::zero());
+        assert_eq!(
+            Gate::empty().evaluate(two, three, six),
+            FieldElement::zero()
+        );
+    }
+
+    #[test]
+    fn test_circuit_interpolation() {
+        let circuit = adder_circuit();
+        assert_eq!(circuit.size(), 4);
+        let domain = circuit.domain();
+        let [a, _, c] = circuit.wire_polynomials();
+        for (i, omega_i) in domain.elements().enumerate().take(3) {
+            let omega_i = FieldElement::from_fr(omega_i);
+            assert_eq!(a.evaluate(omega_i), circuit.a[i]);
+            assert_eq!(c.evaluate(omega_i), circuit.c[i]);
+        }
+        // The padding row is zero
+        let last = FieldElement::from_fr(domain.element(3));
+        assert_eq!(a.evaluate(last), FieldElement::zero());
+    }
+
+    #[test]
+    fn test_gate_constraint_polynomial() {
+        let mut circuit = adder_circuit();
+        let vanishing = Polynomial::vanishing_polynomial(circuit.size());
+        let (_, remainder) = circuit.gate_constraint_polynomial().divide(&vanishing);
+        assert_eq!(remainder, Polynomial::new(vec![FieldElement::zero()]));
+
+        // 3 + 4 != 8
+        circuit.c[1] = FieldElement::new(8);
+        let (_, remainder) = circuit.gate_constraint_polynomial().divide(&vanishing);
+        assert_ne!(remainder, Polynomial::new(vec![FieldElement::zero()]));
+    }
+
+    #[test]
+    fn test_public_input_polynomial() {
+        // Proves knowledge of b with 3·b = 12 for the public 3 and 12
+        let mut circuit = Circuit::new();
+        let three = FieldElement::new(3);
+        let twelve = FieldElement::new(12);
+        circuit.add_public_input(three);
+        circuit.add_public_input(twelve);
+        circuit.add_gate(Gate::mul(), three, FieldElement::new(4), twelve);
+
+        let public = [three, twelve];
+        let pi = circuit.public_input_polynomial(&public);
+        let domain = circuit.domain();
+        for (i, omega_i) in domain.elements().enumerate() {
+            let expected = public.get(i).map_or(FieldElement::zero(), |x| x.negate());
+            assert_eq!(pi.evaluate(FieldElement::from_fr(omega_i)), expected);
+        }
+
+        let vanishing = Polynomial::vanishing_polynomial(circuit.size());
+        let (_, remainder) = circuit.gate_constraint_polynomial().divide(&vanishing);
+        assert_eq!(remainder, Polynomial::new(vec![FieldElement::zero()]));
+
+        // Claiming a different public input breaks the identity
+        circuit.public_inputs[1] = FieldElement::new(13);
+        let (_, remainder) = circuit.gate_constraint_polynomial().divide(&vanishing);
+        assert_ne!(remainder, Polynomial::new(vec![FieldElement::zero()]));
+    }
+
+    #[test]
+    #[should_panic(expected = "Public inputs must come before the gates")]
+    fn test_public_input_after_gate() {
+        let mut circuit = adder_circuit();
+        circuit.add_public_input(FieldElement::one());
+    }
+
+    #[test]
+    fn test_sigma_polynomials_identity() {
+        let circuit = adder_circuit();
+        let n = circuit.size();
+        let [s1, _, s3] = circuit.sigma_polynomials();
+        let [k0, _, k2] = Circuit::coset_shifts();
+        for (i, omega_i) in circuit.domain().elements().enumerate() {
+            let omega_i = FieldElement::from_fr(omega_i);
+            assert_eq!(s1.evaluate(omega_i), k0.multiply(&omega_i));
+            assert_eq!(s3.evaluate(omega_i), k2.multiply(&omega_i));
+            assert_eq!(circuit.wire_label(2 * n + i), k2.multiply(&omega_i));
+        }
+    }
+
+    #[test]
+    fn test_connect_builds_cycles() {
+        // The output of each gate feeds the next: c_0 = a_1, c_1 = a_2 = b_2
+        let mut circuit = adder_circuit();
+        circuit.connect(WireRef::new(Column::C, 0), WireRef::new(Column::A, 1));
+        circuit.connect(WireRef::new(Column::A, 2), WireRef::new(Column::C, 1));
+        circuit.connect(WireRef::new(Column::B, 2), WireRef::new(Column::A, 2));
+
+        let n = circuit.size();
+        let sigma = circuit.permutation();
+        let value = |position: usize| match position / n {
+            0 => circuit.a.get(position % n),
+            1 => circuit.b.get(position % n),
+            _ => circuit.c.get(position % n),
+        };
+
+        // σ is a bijection
+        let mut sorted = sigma.clone();
+        sorted.sort();
+        assert_eq!(sorted, (0..3 * n).collect::<Vec<usize>>());
+
+        // Walk every cycle: connected wires share it, and agree on their value
+        let mut seen = vec![false; 3 * n];
+        let mut cycles = vec![];
+        for start in 0..3 * n {
+            if seen[start] {
+                continue;
+            }
+            let mut cycle = vec![];
+            let mut position = start;
+            while !seen[position] {
+                seen[position] = true;
+                cycle.push(position);
+                assert_eq!(value(position), value(start));
+                position = sigma[position];
+            }
+            assert_eq!(position, start);
+            cycles.push(cycle);
+        }
+        let (c_0, a_1, a_2, b_2, c_1) = (2 * n, 1, 2, n + 2, 2 * n + 1);
+        assert!(cycles.contains(&vec![a_1, c_0]));
+        assert!(cycles.contains(&vec![a_2, b_2, c_1]));
+        assert_eq!(cycles.len(), 3 * n - 3);
+
+        // S_σ1(ω^1) is now the label of c_0
+        let [s1, _, _] = circuit.sigma_polynomials();
+        let omega = FieldElement::from_fr(circuit.domain().element(1));
+        assert_eq!(s1.evaluate(omega), circuit.wire_label(c_0));
+    }
+
+    #[test]
+    #[should_panic(expected = "Wire refers to a row that doesn't exist")]
+    fn test_connect_missing_row() {
+        let mut circuit = adder_circuit();
+        circuit.connect(WireRef::new(Column::A, 0), WireRef::new(Column::C, 3));
+    }
+
+    #[test]
+    fn test_range_gate() {
+        // 13 = 0b1101 in 4 bits: boolean rows 0..4, accumulation rows 4..7
+        let mut circuit = Circuit::new();
+        let out = circuit.add_range_gate(FieldElement::new(13), 4);
+        assert_eq!(out, WireRef::new(Column::C, 6));
+        assert_eq!(circuit.c[6], FieldElement::new(13));
+        assert_eq!(circuit.b[..4], [1, 1, 0, 1].map(FieldElement::new));
+
+        let vanishing = Polynomial::vanishing_polynomial(circuit.size());
+        let (_, remainder) = circuit.gate_constraint_polynomial().divide(&vanishing);
+        assert_eq!(remainder, Polynomial::new(vec![FieldElement::zero()]));
+
+        // 13 = 1·8 + 0·4 + 2·2 + 1: every accumulation still holds, but 2
+        // isn't a bit
+        let mut witness = circuit.witness();
+        witness.b[..4].copy_from_slice(&[1, 0, 2, 1].map(FieldElement::new));
+        witness.b[4] = FieldElement::zero();
+        witness.c[4] = FieldElement::new(2);
+        witness.a[5] = FieldElement::new(2);
+        witness.b[5] = FieldElement::new(2);
+        let circuit = circuit.with_witness(&witness);
+        for row in 4..7 {
+            let gate = circuit.gates[row];
+            let (a, b, c) = (circuit.a[row], circuit.b[row], circuit.c[row]);
+            assert_eq!(gate.evaluate(a, b, c), FieldElement::zero());
+        }
+        let (_, remainder) = circuit.gate_constraint_polynomial().divide(&vanishing);
+        assert_ne!(remainder, Polynomial::new(vec![FieldElement::zero()]));
+    }
+}
@@ -1,7 +1,7 @@
 use super::field::FieldElement;
 use ark_bn254::g1::G1Affine;
-// use ark_bn254::Fr;
-use ark_ec::{AffineRepr, CurveGroup};
+use ark_bn254::{Fr, G1Projective};
+use ark_ec::{AffineRepr, CurveGroup, VariableBaseMSM};
 use ark_ff::Zero;
 use std::ops::{Add, Mul};
 
@@ -62,6 +62,46 @@ impl EllipticCurve {
     pub fn generator(&self) -> Point {
         Point::Affine(self.generator)
     }
+
+    /// Converts many projective points to affine sharing a single field inversion
+    #[allow(dead_code)]
+    pub fn batch_to_affine(points: Vec<G1Projective>) -> Vec<Point> {
+        G1Projective::normalize_batch(&points)
+            .into_iter()
+            .map(|p| {
+                if p.is_zero() {
+                    Point::Infinity
+                } else {
+                    Point::Affine(p)
+                }
+            })
+            .collect()
+    }
+
+    /// Multi-scalar multiplication ∑ [s_i]P_i delegated to ark's VariableBaseMSM.
+    /// Points at infinity contribute nothing, so they are skipped.
+    #[allow(dead_code)]
+    pub fn msm(points: &[Point], scalars: &[FieldElement]) -> Point {
+        assert_eq!(
+            points.len(),
+            scalars.len(),
+            "MSM needs as many scalars as points"
+        );
+        let (bases, scalars): (Vec<G1Affine>, Vec<Fr>) = points
+            .iter()
+            .zip(scalars)
+            .filter_map(|(point, scalar)| match point {
+                Point::Infinity => None,
+                Point::Affine(p) => Some((*p, scalar.value())),
+            })
+            .unzip();
+        let result = G1Projective::msm(&bases, &scalars).expect("Lengths already match");
+        if result.is_zero() {
+            Point::Infinity
+        } else {
+            Point::Affine(result.into_affine())
+        }
+    }
 }
 
 impl Add for Point {
@@ -122,6 +162,59 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn test_scalar_mul_matches_point() {
+        let curve = EllipticCurve::new();
+        let g = curve.generator();
+        assert_eq!(g.scalar_mul(FieldElement::zero(), &curve), Point::Infinity);
+        for k in 0..5 {
+            let scalar = FieldElement::new(k);
+            assert_eq!(g.scalar_mul(scalar, &curve), curve.point(scalar));
+        }
+    }
+
+    #[test]
+    fn test_msm_matches_naive() {
+        let curve = EllipticCurve::new();
+        let points = vec![
+            curve.generator(),
+            curve.point(FieldElement::new(5)),
+            curve.infinity(),
+            curve.point(FieldElement::new(11)),
+        ];
+        let scalars = vec![
+            FieldElement::new(3),
+            FieldElement::new(7),
+            FieldElement::new(9),
+            FieldElement::one().negate(),
+        ];
+        let naive = points
+            .iter()
+            .zip(&scalars)
+            .fold(curve.infinity(), |acc, (p, s)| {
+                acc + p.scalar_mul(*s, &curve)
+            });
+        assert_eq!(EllipticCurve::msm(&points, &scalars), naive);
+        assert_eq!(EllipticCurve::msm(&[], &[]), Point::Infinity);
+    }
+
+    #[test]
+    fn test_batch_to_affine() {
+        let g = G1Affine::generator();
+        let projective: Vec<G1Projective> = (0..6u64).map(|k| g * Fr::from(k)).collect();
+        let individual: Vec<Point> = projective
+            .iter()
+            .map(|p| {
+                if p.is_zero() {
+                    Point::Infinity
+                } else {
+                    Point::Affine(p.into_affine())
+                }
+            })
+            .collect();
+        assert_eq!(EllipticCurve::batch_to_affine(projective), individual);
+    }
+
     #[test]
     fn test_infinity() {
         let curve = EllipticCurve::new();
@@ -142,6 +235,5 @@ mod tests {
     #[test]
     fn test_new() {
         let _curve = EllipticCurve::new();
-        assert!(true);
     }
 }
@@ -1,7 +1,9 @@
+use crate::field_trait;
 use ark_bn254::Fr;
 use ark_ff::{BigInteger256, Field, PrimeField};
 use std::fmt;
 use std::ops::{Add, Div, Mul, Sub}; // Ensure BigInteger256 is imported
+use std::str::FromStr;
 
 #[derive(Clone, PartialEq, Copy)]
 pub struct FieldElement(pub Fr);
@@ -9,7 +11,6 @@ pub struct FieldElement(pub Fr);
 impl FieldElement {
     #[allow(dead_code)]
     pub fn new(value: u64) -> Self {
-        println!("new: fr = {:?}", Fr::from(value));
         FieldElement(Fr::from(value))
     }
 
@@ -67,13 +68,56 @@ impl FieldElement {
 
     #[allow(dead_code)]
     pub fn pow(&self, exp: u64) -> Self {
-        FieldElement(self.0.pow(&[exp]))
+        FieldElement(self.0.pow([exp]))
     }
 
     #[allow(dead_code)]
-    pub fn to_fr(&self) -> Fr {
+    pub fn to_fr(self) -> Fr {
         self.0
     }
+
+    /// Canonical integer representation of the element
+    #[allow(dead_code)]
+    pub fn into_bigint(self) -> BigInteger256 {
+        self.0.into_bigint()
+    }
+
+    /// Canonical value as a u64, or None if it doesn't fit in a single limb
+    #[allow(dead_code)]
+    pub fn to_u64(self) -> Option<u64> {
+        let limbs = self.into_bigint().0;
+        if limbs[1..].iter().all(|limb| *limb == 0) {
+            Some(limbs[0])
+        } else {
+            None
+        }
+    }
+}
+
+impl field_trait::Field for FieldElement {
+    fn zero() -> Self {
+        FieldElement::zero()
+    }
+
+    fn one() -> Self {
+        FieldElement::one()
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        FieldElement::add(self, other)
+    }
+
+    fn sub(&self, other: &Self) -> Self {
+        self.substract(other)
+    }
+
+    fn mul(&self, other: &Self) -> Self {
+        self.multiply(other)
+    }
+
+    fn inverse(&self) -> Self {
+        FieldElement::inverse(self)
+    }
 }
 
 impl fmt::Debug for FieldElement {
@@ -83,6 +127,42 @@ impl fmt::Debug for FieldElement {
     }
 }
 
+impl fmt::Display for FieldElement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0.into_bigint())
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ParseFieldElementError {
+    InvalidDigit,
+    OutOfRange, // value >= Fr::MODULUS
+}
+
+impl FromStr for FieldElement {
+    type Err = ParseFieldElementError;
+
+    /// Parses a decimal string, rejecting values at or above the modulus
+    /// instead of silently reducing them.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(ParseFieldElementError::InvalidDigit);
+        }
+        let digits = s.trim_start_matches('0');
+        let modulus = Fr::MODULUS.to_string();
+        if digits.len() > modulus.len()
+            || (digits.len() == modulus.len() && digits >= modulus.as_str())
+        {
+            return Err(ParseFieldElementError::OutOfRange);
+        }
+        let ten = Fr::from(10u64);
+        let value = digits.bytes().fold(Fr::from(0u64), |acc, b| {
+            acc * ten + Fr::from((b - b'0') as u64)
+        });
+        Ok(FieldElement(value))
+    }
+}
+
 impl Add for FieldElement {
     type Output = Self;
     fn add(self, other: Self) -> Self {
@@ -138,6 +218,58 @@ mod tests {
         assert_eq!(c.0, Fr::from(27));
     }
 
+    #[test]
+    fn test_field_element_value() {
+        assert_eq!(FieldElement::new(3).value(), Fr::from(3u64));
+        assert_eq!(
+            FieldElement::from_fr(Fr::from(7u64)).value(),
+            Fr::from(7u64)
+        );
+    }
+
+    #[test]
+    fn test_field_element_to_u64() {
+        assert_eq!(FieldElement::new(42).to_u64(), Some(42));
+        assert_eq!(
+            FieldElement::new(42).into_bigint(),
+            BigInteger256::from(42u64)
+        );
+        // -1 is the modulus minus one, far above u64::MAX
+        assert_eq!(FieldElement::one().negate().to_u64(), None);
+    }
+
+    #[test]
+    fn test_field_element_display_round_trip() {
+        let elements = [
+            FieldElement::zero(),
+            FieldElement::new(42),
+            FieldElement::new(u64::MAX).multiply(&FieldElement::new(u64::MAX)),
+            FieldElement::one().negate(),
+        ];
+        for x in elements {
+            assert_eq!(format!("{}", x).parse::<FieldElement>(), Ok(x));
+        }
+        assert_eq!(format!("{}", FieldElement::new(42)), "42");
+    }
+
+    #[test]
+    fn test_field_element_from_str_rejects() {
+        let modulus = Fr::MODULUS.to_string();
+        assert_eq!(
+            modulus.parse::<FieldElement>(),
+            Err(ParseFieldElementError::OutOfRange)
+        );
+        assert_eq!(
+            "12a".parse::<FieldElement>(),
+            Err(ParseFieldElementError::InvalidDigit)
+        );
+        assert_eq!(
+            "".parse::<FieldElement>(),
+            Err(ParseFieldElementError::InvalidDigit)
+        );
+        assert_eq!("007".parse::<FieldElement>(), Ok(FieldElement::new(7)));
+    }
+
     #[test]
     fn test_field_element_inverse() {
         let a = FieldElement::new(5);
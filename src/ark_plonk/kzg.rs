@@ -1 +1,139 @@
+use super::elliptic_curve::{EllipticCurve, Point};
+use super::field::FieldElement;
+use super::polynomial::Polynomial;
+use ark_bn254::{Bn254, Fr, G1Affine, G1Projective, G2Affine, G2Projective};
+use ark_ec::pairing::Pairing;
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::UniformRand;
+use ark_std::rand;
+use std::ops::Mul;
 
+/*
+    KZG over BN254: the SRS holds [τ^i]G1 for i up to the supported degree and
+    [1]G2, [τ]G2. An opening of p at z is the commitment to
+    q(x) = (p(x) - y) / (x - z), checked with
+
+        e(C - [y]G1, G2) == e(π, [τ]G2 - [z]G2)
+*/
+
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Clone, Debug)]
+pub struct KZG {
+    powers_g1: Vec<Point>, // [1]G1, [τ]G1, ..., [τ^d]G1
+    g2: G2Affine,
+    tau_g2: G2Affine,
+}
+
+impl KZG {
+    /// Trusted setup with a fresh random τ
+    #[allow(dead_code)]
+    pub fn new(degree: usize) -> Self {
+        let tau = FieldElement::from_fr(Fr::rand(&mut rand::thread_rng()));
+        Self::new_with_tau(degree, tau)
+    }
+
+    /// Trusted setup with an explicit secret. Only for tests: anyone who
+    /// knows τ can forge openings.
+    #[allow(dead_code)]
+    pub fn new_with_tau(degree: usize, tau: FieldElement) -> Self {
+        let g1 = G1Affine::generator();
+        let g2 = G2Affine::generator();
+        let mut powers = Vec::with_capacity(degree + 1);
+        let mut power = FieldElement::one();
+        for _ in 0..=degree {
+            powers.push(g1.mul(power.value()));
+            power = power.multiply(&tau);
+        }
+        KZG {
+            powers_g1: EllipticCurve::batch_to_affine(powers),
+            g2,
+            tau_g2: g2.mul(tau.value()).into_affine(),
+        }
+    }
+
+    /// Highest degree the SRS can commit to
+    #[allow(dead_code)]
+    pub fn max_degree(&self) -> usize {
+        self.powers_g1.len() - 1
+    }
+
+    /// ∑ [p_i]·[τ^i]G1
+    #[allow(dead_code)]
+    pub fn commit(&self, poly: &Polynomial) -> Point {
+        assert!(
+            poly.coeffs.len() <= self.powers_g1.len(),
+            "Polynomial of degree {} doesn't fit in an SRS of degree {}",
+            poly.coeffs.len() - 1,
+            self.max_degree()
+        );
+        EllipticCurve::msm(&self.powers_g1[..poly.coeffs.len()], &poly.coeffs)
+    }
+
+    /// Returns y = p(z) and the proof [q(τ)]G1
+    #[allow(dead_code)]
+    pub fn open(&self, poly: &Polynomial, z: FieldElement) -> (FieldElement, Point) {
+        let (y, quotient) = poly.evaluate_with_quotient(z);
+        (y, self.commit(&quotient))
+    }
+
+    #[allow(dead_code)]
+    pub fn verify(
+        &self,
+        commitment: &Point,
+        z: FieldElement,
+        y: FieldElement,
+        proof: &Point,
+    ) -> bool {
+        let y_g1 = G1Affine::generator().mul(y.value());
+        let left = to_projective(commitment) - y_g1;
+        let right = G2Projective::from(self.tau_g2) - self.g2.mul(z.value());
+        Bn254::pairing(left, self.g2) == Bn254::pairing(to_projective(proof), right)
+    }
+}
+
+fn to_projective(point: &Point) -> G1Projective {
+    match point {
+        Point::Infinity => G1Projective::default(),
+        Point::Affine(p) => G1Projective::from(*p),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kzg_open_and_verify() {
+        let kzg = KZG::new(4);
+        // P(x) = 5 + 3x + 2x^2 + 7x^3
+        let p = Polynomial::new(vec![
+            FieldElement::new(5),
+            FieldElement::new(3),
+            FieldElement::new(2),
+            FieldElement::new(7),
+        ]);
+        let commitment = kzg.commit(&p);
+        let z = FieldElement::new(4);
+        let (y, proof) = kzg.open(&p, z);
+        assert_eq!(y, p.evaluate(z));
+        assert!(kzg.verify(&commitment, z, y, &proof));
+        assert!(!kzg.verify(&commitment, z, y + FieldElement::one(), &proof));
+        assert!(!kzg.verify(&commitment, FieldElement::new(5), y, &proof));
+    }
+
+    #[test]
+    fn test_kzg_commit_is_homomorphic() {
+        let kzg = KZG::new_with_tau(2, FieldElement::new(9));
+        let p = Polynomial::new(vec![FieldElement::new(1), FieldElement::new(2)]);
+        let q = Polynomial::new(vec![FieldElement::new(3), FieldElement::new(4)]);
+        assert_eq!(
+            kzg.commit(&(p.clone() + q.clone())),
+            kzg.commit(&p) + kzg.commit(&q)
+        );
+        // p(9) = 19 in the exponent
+        assert_eq!(
+            kzg.commit(&p),
+            EllipticCurve::new().point(FieldElement::new(19))
+        );
+    }
+}
@@ -1,4 +1,8 @@
 use super::field::FieldElement;
+use crate::generic_polynomial::Polynomial as GenericPolynomial;
+use ark_bn254::Fr;
+use ark_poly::univariate::DensePolynomial;
+use ark_poly::DenseUVPolynomial;
 use std::ops::{Add, Mul, Sub};
 
 #[derive(Clone, Debug, PartialEq)]
@@ -52,30 +56,7 @@ impl Polynomial {
     /// where l_i(x)= ∏_j≠i (x−xj)/(xi−xj)
     #[allow(dead_code)]
     pub fn lagrange_interpolate(points: &[(FieldElement, FieldElement)]) -> Self {
-        assert!(!points.is_empty(), "Need at least one point");
-        let mut result = Polynomial::new(vec![]);
-
-        for (i, &(ref xi, ref yi)) in points.iter().enumerate() {
-            let mut term = Polynomial::new(vec![FieldElement::one()]); // Start with 1
-            let mut denominator = FieldElement::one();
-
-            for (j, &(ref xj, _)) in points.iter().enumerate() {
-                if i != j {
-                    // Numerator: (x - xj)
-                    let num = Polynomial::new(vec![xj.clone().negate(), FieldElement::one()]);
-                    term = term * num;
-                    // Denominator: (xi - xj)
-                    denominator = denominator.multiply(&xi.substract(xj));
-                }
-            }
-
-            let inv_denominator = denominator.inverse();
-            term = term.scalar_mul(inv_denominator);
-            term = term.scalar_mul(yi.clone());
-            result = result + term;
-        }
-
-        result
+        GenericPolynomial::lagrange_interpolate(points).into()
     }
 
     #[allow(dead_code)]
@@ -84,68 +65,86 @@ impl Polynomial {
             .iter()
             .rev()
             .fold(FieldElement::zero(), |acc, coeff| {
-                acc.multiply(&x).add(coeff.clone())
+                acc.multiply(&x).add(*coeff)
             })
     }
 
+    /// Synthetic division by (x - z) in a single Horner pass.
+    /// Returns p(z) and the quotient (p(x) - p(z)) / (x - z).
     #[allow(dead_code)]
-    pub fn divide(&self, divisor: &Self) -> (Self, Self) {
-        assert!(
-            !divisor.coeffs.is_empty(),
-            "Cannot divide by zero polynomial"
-        );
-
-        // If divisor is a constant (degree 0), handle scalar division
-        if divisor.coeffs.len() == 1 {
-            let scalar: &FieldElement = &divisor.coeffs[0];
-            let inv_scalar: FieldElement = scalar.inverse();
-            let quotient_coeffs: Vec<FieldElement> = self
-                .coeffs
-                .iter()
-                .map(|c| c.multiply(&inv_scalar))
-                .collect();
-            return (
-                Polynomial::new(quotient_coeffs),
-                Polynomial::new(vec![]), // Remainder is 0
-            );
+    pub fn evaluate_with_quotient(&self, z: FieldElement) -> (FieldElement, Self) {
+        let mut quotient: Vec<FieldElement> =
+            vec![FieldElement::zero(); self.coeffs.len().saturating_sub(1)];
+        let mut acc = FieldElement::zero();
+        for (i, coeff) in self.coeffs.iter().enumerate().rev() {
+            acc = acc.multiply(&z).add(*coeff);
+            if i > 0 {
+                quotient[i - 1] = acc;
+            }
         }
+        (acc, Polynomial::new(quotient))
+    }
 
-        let mut dividend: Vec<FieldElement> = self.coeffs.clone();
-        let divisor_deg: usize = divisor.coeffs.len() - 1;
-        let divisor_lead: &FieldElement = divisor.coeffs.last().unwrap(); // Leading coefficient
-        let inv_divisor_lead: FieldElement = divisor_lead.inverse();
+    /// Long division, returns (quotient, remainder)
+    #[allow(dead_code)]
+    pub fn divide(&self, divisor: &Self) -> (Self, Self) {
+        let (quotient, remainder) =
+            GenericPolynomial::from(self.clone()).divide(&divisor.clone().into());
+        (quotient.into(), Self::remainder(remainder.coeffs))
+    }
 
-        // If dividend degree < divisor degree, quotient is 0, remainder is dividend
-        if dividend.len() <= divisor_deg {
-            return (Polynomial::new(vec![]), Polynomial::new(dividend));
+    /// Trims every trailing zero of a division remainder, keeping a single
+    /// zero coefficient for the zero remainder. `Polynomial::new` doesn't trim
+    /// and PartialEq compares coefficients, so stale leading zeros would make
+    /// equal remainders compare unequal.
+    fn remainder(mut coeffs: Vec<FieldElement>) -> Self {
+        while coeffs.last() == Some(&FieldElement::zero()) {
+            coeffs.pop();
         }
-
-        let mut quotient: Vec<FieldElement> =
-            vec![FieldElement::zero(); dividend.len() - divisor_deg];
-        for i in (0..quotient.len()).rev() {
-            let dividend_deg = i + divisor_deg;
-            let term = dividend[dividend_deg].clone().multiply(&inv_divisor_lead);
-            quotient[i] = term.clone();
-
-            // Subtract term * divisor from dividend
-            for j in 0..divisor.coeffs.len() {
-                let prod: FieldElement = divisor.coeffs[j].clone().multiply(&term);
-                dividend[dividend_deg - (divisor_deg - j)] = dividend
-                    [dividend_deg - (divisor_deg - j)]
-                    .clone()
-                    .substract(&prod);
-            }
+        if coeffs.is_empty() {
+            coeffs.push(FieldElement::zero());
         }
+        Polynomial::new(coeffs)
+    }
+
+    /// The same coefficients as an arkworks polynomial, to use its FFT based
+    /// arithmetic. Trailing zero coefficients are dropped.
+    #[allow(dead_code)]
+    pub fn to_ark(&self) -> DensePolynomial<Fr> {
+        DensePolynomial::from_coefficients_vec(self.coeffs.iter().map(|c| c.value()).collect())
+    }
 
-        // Trim leading zeros from remainder
-        while dividend.len() > divisor_deg && dividend.last().unwrap() == &FieldElement::zero() {
-            dividend.pop();
+    /// Inverse of `to_ark`. The zero polynomial comes back as [0].
+    #[allow(dead_code)]
+    pub fn from_ark(poly: DensePolynomial<Fr>) -> Self {
+        if poly.coeffs.is_empty() {
+            return Polynomial::new(vec![FieldElement::zero()]);
         }
+        Polynomial::new(poly.coeffs.into_iter().map(FieldElement::from_fr).collect())
+    }
+}
+
+/// Division and interpolation are shared with the other backend through
+/// `generic_polynomial`, the conversions only move the coefficients
+impl From<Polynomial> for GenericPolynomial<FieldElement> {
+    fn from(poly: Polynomial) -> Self {
+        GenericPolynomial::new(poly.coeffs)
+    }
+}
 
-        (Polynomial::new(quotient), Polynomial::new(dividend))
+impl From<GenericPolynomial<FieldElement>> for Polynomial {
+    fn from(poly: GenericPolynomial<FieldElement>) -> Self {
+        Polynomial::new(poly.coeffs)
     }
 }
 
+/// Evaluates x^n - 1 at z directly, O(log n) instead of building the
+/// vanishing polynomial and running Horner over its n + 1 coefficients.
+#[allow(dead_code)]
+pub fn evaluate_vanishing(n: usize, z: &FieldElement) -> FieldElement {
+    z.pow(n as u64).substract(&FieldElement::one())
+}
+
 impl Add for Polynomial {
     type Output = Self;
     fn add(self, other: Self) -> Self {
@@ -249,6 +248,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_evaluate_vanishing() {
+        use ark_ff::FftField;
+
+        let n = 8;
+        let omega = FieldElement::from_fr(ark_bn254::Fr::get_root_of_unity(n as u64).unwrap());
+        for i in 0..n as u64 {
+            assert_eq!(evaluate_vanishing(n, &omega.pow(i)), FieldElement::zero());
+        }
+
+        let z = FieldElement::new(12345);
+        assert_eq!(
+            evaluate_vanishing(n, &z),
+            Polynomial::vanishing_polynomial(n).evaluate(z)
+        );
+        assert_ne!(evaluate_vanishing(n, &z), FieldElement::zero());
+    }
+
     #[test]
     fn test_lagrange_interpolate() {
         let points = vec![
@@ -258,10 +275,28 @@ mod tests {
         ];
         let p = Polynomial::lagrange_interpolate(&points);
         for (x, y) in points {
-            assert_eq!(p.evaluate(x.clone()), y);
+            assert_eq!(p.evaluate(x), y);
         }
     }
 
+    #[test]
+    fn test_evaluate_with_quotient() {
+        // P(x) = 5 + 3x + 2x^2 + 7x^3
+        let p = Polynomial::new(vec![
+            FieldElement::new(5),
+            FieldElement::new(3),
+            FieldElement::new(2),
+            FieldElement::new(7),
+        ]);
+        let z = FieldElement::new(4);
+        let (y, q) = p.evaluate_with_quotient(z);
+        assert_eq!(y, p.evaluate(z));
+
+        let x_minus_z = Polynomial::new(vec![z.negate(), FieldElement::one()]);
+        let p_minus_y = p - Polynomial::new(vec![y]);
+        assert_eq!(q * x_minus_z, p_minus_y);
+    }
+
     #[test]
     fn test_polynomial_divide() {
         // P(x) = 1 + 2x + 3x^2
@@ -281,4 +316,58 @@ mod tests {
         ); // -4 = 13 mod 17 if p=17, else adjust
         assert_eq!(r.coeffs, vec![FieldElement::new(9)]);
     }
+
+    #[test]
+    fn test_divide_zero_remainder() {
+        // x^2 - 1 = (x - 1)(x + 1)
+        let p = Polynomial::vanishing_polynomial(2);
+        let d = Polynomial::new(vec![FieldElement::one().negate(), FieldElement::one()]);
+        let (q, r) = p.divide(&d);
+        assert_eq!(
+            q,
+            Polynomial::new(vec![FieldElement::one(), FieldElement::one()])
+        );
+        assert_eq!(r, Polynomial::new(vec![FieldElement::zero()]));
+
+        // Same for a constant divisor and for a dividend of lower degree
+        let (_, r) = d.divide(&Polynomial::new(vec![FieldElement::new(2)]));
+        assert_eq!(r, Polynomial::new(vec![FieldElement::zero()]));
+        let (_, r) = Polynomial::new(vec![FieldElement::zero(), FieldElement::zero()]).divide(&d);
+        assert_eq!(r, Polynomial::new(vec![FieldElement::zero()]));
+        let (_, r) = Polynomial::new(vec![]).divide(&d);
+        assert_eq!(r, Polynomial::new(vec![FieldElement::zero()]));
+    }
+
+    #[test]
+    fn test_ark_round_trip() {
+        let p = Polynomial::new(vec![
+            FieldElement::new(5),
+            FieldElement::new(0),
+            FieldElement::new(7).negate(),
+        ]);
+        let ark = p.to_ark();
+        assert_eq!(ark.coeffs[2], FieldElement::new(7).negate().value());
+        assert_eq!(Polynomial::from_ark(ark), p);
+
+        // ark trims trailing zeros, the zero polynomial has no coefficients
+        let padded = Polynomial::new(vec![FieldElement::one(), FieldElement::zero()]);
+        assert_eq!(
+            Polynomial::from_ark(padded.to_ark()),
+            Polynomial::new(vec![FieldElement::one()])
+        );
+        let zero = Polynomial::new(vec![FieldElement::zero(); 3]);
+        assert!(zero.to_ark().coeffs.is_empty());
+        assert_eq!(
+            Polynomial::from_ark(zero.to_ark()),
+            Polynomial::new(vec![FieldElement::zero()])
+        );
+    }
+
+    #[test]
+    fn test_ark_mul_matches_local_mul() {
+        let p = Polynomial::new((1..=20).map(FieldElement::new).collect());
+        let q = Polynomial::new((0..33).map(|i| FieldElement::new(i * i + 3)).collect());
+        let ark_product = &p.to_ark() * &q.to_ark();
+        assert_eq!(Polynomial::from_ark(ark_product), p * q);
+    }
 }
@@ -0,0 +1,516 @@
+use super::circuit::{Circuit, Witness};
+use super::elliptic_curve::Point;
+use super::field::FieldElement;
+use super::kzg::KZG;
+use super::polynomial::Polynomial;
+use super::transcript::Transcript;
+use ark_bn254::{Fr, G1Affine};
+use ark_ec::AffineRepr;
+use ark_poly::EvaluationDomain;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+
+/*
+    Permutation argument: with random β, γ the grand product
+
+        z(ω^0) = 1
+        z(ω^(i+1)) = z(ω^i) · ∏_j (w_j(ω^i) + β·k_j·ω^i + γ) / (w_j(ω^i) + β·S_σj(ω^i) + γ)
+
+    wraps around to 1 iff the wire values are invariant under σ. The quotient
+    t(x) packs the gate identity and both permutation identities, separated
+    by powers of α, and is a polynomial iff all of them vanish on H:
+
+        t(x) = [gate(x)
+                + α·(z(x)·∏_j (w_j(x) + β·k_j·x + γ) - z(ωx)·∏_j (w_j(x) + β·S_σj(x) + γ))
+                + α^2·L_0(x)·(z(x) - 1)] / (x^n - 1)
+*/
+
+/// Label the prover and verifier transcripts start from
+pub const TRANSCRIPT_LABEL: &[u8] = b"my-rusty-plonk";
+
+/// Commitments to the wires, z and t, the evaluations at the challenge ζ the
+/// verifier needs to build [r] (z only at ζω), and the two KZG opening proofs
+#[derive(Clone, Debug, PartialEq)]
+pub struct Proof {
+    pub a_commit: Point,
+    pub b_commit: Point,
+    pub c_commit: Point,
+    pub z_commit: Point,
+    pub t_commit: Point,
+    pub a_eval: FieldElement,
+    pub b_eval: FieldElement,
+    pub c_eval: FieldElement,
+    pub s1_eval: FieldElement,
+    pub s2_eval: FieldElement,
+    pub z_omega_eval: FieldElement,
+    pub opening_proof: Point, // r + v·a + v^2·b + v^3·c + v^4·S_σ1 + v^5·S_σ2 opened at ζ
+    pub z_omega_proof: Point, // z opened at ζω
+}
+
+impl Proof {
+    /// Compressed encoding: the five commitments and the two opening proofs
+    /// as G1 points, then the six evaluations, each group in field order
+    #[allow(dead_code)]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![];
+        for point in self.points() {
+            let affine = match point {
+                Point::Infinity => G1Affine::zero(),
+                Point::Affine(p) => *p,
+            };
+            affine
+                .serialize_compressed(&mut bytes)
+                .expect("Writing to a Vec can't fail");
+        }
+        for eval in self.evaluations() {
+            eval.value()
+                .serialize_compressed(&mut bytes)
+                .expect("Writing to a Vec can't fail");
+        }
+        bytes
+    }
+
+    /// Inverse of `to_bytes`. Fails on points off the curve or outside the
+    /// prime order subgroup, non-canonical scalars and trailing bytes.
+    #[allow(dead_code)]
+    pub fn from_bytes(bytes: &[u8]) -> Result<Proof, SerializationError> {
+        let mut reader = bytes;
+        let mut point = || -> Result<Point, SerializationError> {
+            let affine = G1Affine::deserialize_compressed(&mut reader)?;
+            Ok(if affine.is_zero() {
+                Point::Infinity
+            } else {
+                Point::Affine(affine)
+            })
+        };
+        let (a_commit, b_commit, c_commit, z_commit, t_commit) =
+            (point()?, point()?, point()?, point()?, point()?);
+        let (opening_proof, z_omega_proof) = (point()?, point()?);
+        let mut scalar = || -> Result<FieldElement, SerializationError> {
+            Ok(FieldElement::from_fr(Fr::deserialize_compressed(
+                &mut reader,
+            )?))
+        };
+        let proof = Proof {
+            a_commit,
+            b_commit,
+            c_commit,
+            z_commit,
+            t_commit,
+            a_eval: scalar()?,
+            b_eval: scalar()?,
+            c_eval: scalar()?,
+            s1_eval: scalar()?,
+            s2_eval: scalar()?,
+            z_omega_eval: scalar()?,
+            opening_proof,
+            z_omega_proof,
+        };
+        if !reader.is_empty() {
+            return Err(SerializationError::InvalidData);
+        }
+        Ok(proof)
+    }
+
+    fn points(&self) -> [&Point; 7] {
+        [
+            &self.a_commit,
+            &self.b_commit,
+            &self.c_commit,
+            &self.z_commit,
+            &self.t_commit,
+            &self.opening_proof,
+            &self.z_omega_proof,
+        ]
+    }
+
+    fn evaluations(&self) -> [&FieldElement; 6] {
+        [
+            &self.a_eval,
+            &self.b_eval,
+            &self.c_eval,
+            &self.s1_eval,
+            &self.s2_eval,
+            &self.z_omega_eval,
+        ]
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Prover {
+    pub circuit: Circuit,
+    pub beta: FieldElement,
+    pub gamma: FieldElement,
+}
+
+impl Prover {
+    #[allow(dead_code)]
+    pub fn new(circuit: Circuit, beta: FieldElement, gamma: FieldElement) -> Self {
+        Prover {
+            circuit,
+            beta,
+            gamma,
+        }
+    }
+
+    /// Wire values of the 3n positions, padding rows zero
+    fn wire_values(&self) -> Vec<FieldElement> {
+        let n = self.circuit.size();
+        let mut values = Vec::with_capacity(3 * n);
+        for column in [&self.circuit.a, &self.circuit.b, &self.circuit.c] {
+            values.extend(column);
+            values.resize(values.len() + n - column.len(), FieldElement::zero());
+        }
+        values
+    }
+
+    /// The grand product z(x) of the permutation argument
+    #[allow(dead_code)]
+    pub fn permutation_polynomial(&self) -> Polynomial {
+        let n = self.circuit.size();
+        let values = self.wire_values();
+        let sigma = self.circuit.permutation();
+        let mut evals = vec![FieldElement::one()];
+        for i in 0..n - 1 {
+            let mut numerator = FieldElement::one();
+            let mut denominator = FieldElement::one();
+            for j in 0..3 {
+                let position = j * n + i;
+                let id = self.circuit.wire_label(position);
+                let sigma_label = self.circuit.wire_label(sigma[position]);
+                numerator = numerator.multiply(&(values[position] + self.beta * id + self.gamma));
+                denominator = denominator
+                    .multiply(&(values[position] + self.beta * sigma_label + self.gamma));
+            }
+            evals.push(evals[i].multiply(&numerator).divide(&denominator));
+        }
+        self.circuit.interpolate(&evals)
+    }
+
+    /// gate(x) + α·(permutation identity) + α^2·L_0(x)(z(x) - 1)
+    fn quotient_numerator(&self, alpha: FieldElement) -> Polynomial {
+        let [a, b, c] = self.circuit.wire_polynomials();
+        let [s1, s2, s3] = self.circuit.sigma_polynomials();
+        let [k0, k1, k2] = Circuit::coset_shifts();
+        let z = self.permutation_polynomial();
+        let omega = FieldElement::from_fr(self.circuit.domain().group_gen());
+        let z_omega = shift(&z, omega);
+        let x = Polynomial::new(vec![FieldElement::zero(), FieldElement::one()]);
+
+        // w(x) + β·label(x) + γ
+        let wire_term = |w: Polynomial, label: Polynomial| {
+            w + label.scalar_mul(self.beta) + constant(self.gamma)
+        };
+        let identity = z.clone()
+            * wire_term(a.clone(), x.scalar_mul(k0))
+            * wire_term(b.clone(), x.scalar_mul(k1))
+            * wire_term(c.clone(), x.scalar_mul(k2));
+        let permuted = z_omega * wire_term(a, s1) * wire_term(b, s2) * wire_term(c, s3);
+
+        let l_0 = self.circuit.interpolate(&[FieldElement::one()]);
+        let starts_at_one = l_0 * (z - constant(FieldElement::one()));
+
+        self.circuit.gate_constraint_polynomial()
+            + (identity - permuted).scalar_mul(alpha)
+            + starts_at_one.scalar_mul(alpha.multiply(&alpha))
+    }
+
+    /// t(x) for the given α. Panics if the witness doesn't satisfy the circuit,
+    /// the division by x^n - 1 leaves a remainder then.
+    #[allow(dead_code)]
+    pub fn compute_quotient(&self, alpha: FieldElement) -> Polynomial {
+        let vanishing = Polynomial::vanishing_polynomial(self.circuit.size());
+        let (quotient, remainder) = self.quotient_numerator(alpha).divide(&vanishing);
+        assert_eq!(
+            remainder,
+            Polynomial::new(vec![FieldElement::zero()]),
+            "Witness doesn't satisfy the circuit"
+        );
+        quotient
+    }
+
+    /// Linearization r(x) at ζ: the quotient identity with every product of
+    /// polynomials replaced by evaluations at ζ, so that only one factor of
+    /// each term stays a polynomial. It is linear in q_*, S_σ3, z and t, so
+    /// [r] follows from their commitments, and r(ζ) = 0 for an honest prover.
+    /// `prove` opens r at ζ instead of sending t(ζ).
+    ///
+    ///   r(x) = ā·b̄·q_M(x) + ā·q_L(x) + b̄·q_R(x) + c̄·q_O(x) + q_C(x)
+    ///        + b̄·(b̄ - 1)·q_range(x) + PI(ζ)
+    ///        + α·(ā + βζ + γ)(b̄ + βk_1ζ + γ)(c̄ + βk_2ζ + γ)·z(x)
+    ///        - α·(ā + βs̄_1 + γ)(b̄ + βs̄_2 + γ)·z̄_ω·(c̄ + β·S_σ3(x) + γ)
+    ///        + α^2·L_0(ζ)·(z(x) - 1)
+    ///        - (ζ^n - 1)·t(x)
+    #[allow(dead_code)]
+    pub fn linearization_polynomial(&self, alpha: FieldElement, zeta: FieldElement) -> Polynomial {
+        let n = self.circuit.size();
+        let [a, b, c] = self.circuit.wire_polynomials().map(|w| w.evaluate(zeta));
+        let [q_m, q_l, q_r, q_o, q_c, q_range] = self.circuit.selector_polynomials();
+        let [s1, s2, s3] = self.circuit.sigma_polynomials();
+        let (s1, s2) = (s1.evaluate(zeta), s2.evaluate(zeta));
+        let [k0, k1, k2] = Circuit::coset_shifts();
+        let (beta, gamma) = (self.beta, self.gamma);
+        let z = self.permutation_polynomial();
+        let omega = FieldElement::from_fr(self.circuit.domain().group_gen());
+        let z_omega = z.evaluate(zeta.multiply(&omega));
+        let pi = self
+            .circuit
+            .public_input_polynomial(&self.circuit.public_inputs)
+            .evaluate(zeta);
+        let vanishing = Polynomial::vanishing_polynomial(n);
+        let (t, _) = self.quotient_numerator(alpha).divide(&vanishing);
+        let vanishing_at_zeta = vanishing.evaluate(zeta);
+        let l_0 = self
+            .circuit
+            .interpolate(&[FieldElement::one()])
+            .evaluate(zeta);
+
+        let gate = q_m.scalar_mul(a * b)
+            + q_l.scalar_mul(a)
+            + q_r.scalar_mul(b)
+            + q_o.scalar_mul(c)
+            + q_c
+            + q_range.scalar_mul(b * (b - FieldElement::one()))
+            + constant(pi);
+        let identity = (a + beta * k0 * zeta + gamma)
+            * (b + beta * k1 * zeta + gamma)
+            * (c + beta * k2 * zeta + gamma);
+        let permuted = (a + beta * s1 + gamma) * (b + beta * s2 + gamma) * z_omega;
+        let permutation = z.scalar_mul(identity)
+            - (s3.scalar_mul(beta) + constant(c + gamma)).scalar_mul(permuted);
+        let starts_at_one = (z - constant(FieldElement::one())).scalar_mul(l_0);
+
+        gate + permutation.scalar_mul(alpha) + starts_at_one.scalar_mul(alpha * alpha)
+            - t.scalar_mul(vanishing_at_zeta)
+    }
+
+    /// Proves the circuit is satisfied by the witness. The quotient is taken
+    /// without checking the remainder: an unsatisfying witness doesn't panic,
+    /// it just yields a proof the verifier rejects.
+    #[allow(dead_code)]
+    pub fn prove(kzg: &KZG, circuit: &Circuit, witness: &Witness) -> Proof {
+        let circuit = circuit.with_witness(witness);
+        let mut transcript = Transcript::new(TRANSCRIPT_LABEL);
+        for x in &circuit.public_inputs {
+            transcript.append_scalar(b"public input", x);
+        }
+
+        // Round 1: wires
+        let [a, b, c] = circuit.wire_polynomials();
+        let (a_commit, b_commit, c_commit) = (kzg.commit(&a), kzg.commit(&b), kzg.commit(&c));
+        transcript.append_point(b"a", &a_commit);
+        transcript.append_point(b"b", &b_commit);
+        transcript.append_point(b"c", &c_commit);
+        let beta = transcript.challenge(b"beta");
+        let gamma = transcript.challenge(b"gamma");
+
+        // Round 2: grand product
+        let prover = Prover::new(circuit, beta, gamma);
+        let z = prover.permutation_polynomial();
+        let z_commit = kzg.commit(&z);
+        transcript.append_point(b"z", &z_commit);
+        let alpha = transcript.challenge(b"alpha");
+
+        // Round 3: quotient
+        let vanishing = Polynomial::vanishing_polynomial(prover.circuit.size());
+        let (t, _) = prover.quotient_numerator(alpha).divide(&vanishing);
+        let t_commit = kzg.commit(&t);
+        transcript.append_point(b"t", &t_commit);
+        let zeta = transcript.challenge(b"zeta");
+
+        // Round 4: evaluations
+        let omega = FieldElement::from_fr(prover.circuit.domain().group_gen());
+        let zeta_omega = zeta.multiply(&omega);
+        let (a_eval, b_eval, c_eval) = (a.evaluate(zeta), b.evaluate(zeta), c.evaluate(zeta));
+        let [s1, s2, _] = prover.circuit.sigma_polynomials();
+        let (s1_eval, s2_eval) = (s1.evaluate(zeta), s2.evaluate(zeta));
+        let z_omega_eval = z.evaluate(zeta_omega);
+        for (label, eval) in [
+            (b"a_eval", &a_eval),
+            (b"b_eval", &b_eval),
+            (b"c_eval", &c_eval),
+        ] {
+            transcript.append_scalar(label, eval);
+        }
+        transcript.append_scalar(b"s1_eval", &s1_eval);
+        transcript.append_scalar(b"s2_eval", &s2_eval);
+        transcript.append_scalar(b"z_omega_eval", &z_omega_eval);
+        let v = transcript.challenge(b"v");
+
+        // Round 5: openings, the ones at ζ batched with powers of v. r(ζ) = 0
+        // takes the place of t(ζ), the verifier builds [r] itself.
+        let r = prover.linearization_polynomial(alpha, zeta);
+        let mut combined = Polynomial::new(vec![]);
+        let mut v_power = FieldElement::one();
+        for p in [&r, &a, &b, &c, &s1, &s2] {
+            combined = combined + p.scalar_mul(v_power);
+            v_power = v_power.multiply(&v);
+        }
+        let (_, opening_proof) = kzg.open(&combined, zeta);
+        let (_, z_omega_proof) = kzg.open(&z, zeta_omega);
+
+        Proof {
+            a_commit,
+            b_commit,
+            c_commit,
+            z_commit,
+            t_commit,
+            a_eval,
+            b_eval,
+            c_eval,
+            s1_eval,
+            s2_eval,
+            z_omega_eval,
+            opening_proof,
+            z_omega_proof,
+        }
+    }
+}
+
+fn constant(c: FieldElement) -> Polynomial {
+    Polynomial::new(vec![c])
+}
+
+/// p(ωx): the i-th coefficient gets multiplied by ω^i
+fn shift(p: &Polynomial, omega: FieldElement) -> Polynomial {
+    let mut power = FieldElement::one();
+    let coeffs = p
+        .coeffs
+        .iter()
+        .map(|coeff| {
+            let shifted = coeff.multiply(&power);
+            power = power.multiply(&omega);
+            shifted
+        })
+        .collect();
+    Polynomial::new(coeffs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ark_plonk::circuit::{Column, Gate, WireRef};
+    use crate::ark_plonk::elliptic_curve::EllipticCurve;
+
+    /// Public output 35 of x^3 + x + 5 for x = 3
+    fn cubic_circuit() -> Circuit {
+        let x = FieldElement::new(3);
+        let x_2 = x * x;
+        let x_3 = x_2 * x;
+        let mut circuit = Circuit::new();
+        circuit.add_public_input(FieldElement::new(35));
+        circuit.add_gate(Gate::mul(), x, x, x_2);
+        circuit.add_gate(Gate::mul(), x_2, x, x_3);
+        circuit.add_gate(Gate::add(), x_3, x, x_3 + x);
+        let add_5 = Gate {
+            q_l: FieldElement::one(),
+            q_o: FieldElement::one().negate(),
+            q_c: FieldElement::new(5),
+            ..Gate::empty()
+        };
+        circuit.add_gate(add_5, x_3 + x, FieldElement::zero(), FieldElement::new(35));
+
+        // x in every input that uses it, each output into the next gate, and
+        // the final output into the public input
+        let wire = WireRef::new;
+        for (u, v) in [
+            (wire(Column::A, 1), wire(Column::B, 1)),
+            (wire(Column::A, 1), wire(Column::B, 2)),
+            (wire(Column::A, 1), wire(Column::B, 3)),
+            (wire(Column::C, 1), wire(Column::A, 2)),
+            (wire(Column::C, 2), wire(Column::A, 3)),
+            (wire(Column::C, 3), wire(Column::A, 4)),
+            (wire(Column::C, 4), wire(Column::A, 0)),
+        ] {
+            circuit.connect(u, v);
+        }
+        circuit
+    }
+
+    #[test]
+    fn test_compute_quotient_divides_exactly() {
+        let circuit = cubic_circuit();
+        let n = circuit.size();
+        let prover = Prover::new(circuit, FieldElement::new(7), FieldElement::new(11));
+        let alpha = FieldElement::new(13);
+        let t = prover.compute_quotient(alpha);
+        let numerator = prover.quotient_numerator(alpha);
+        assert!(t.degree().unwrap() <= 3 * n);
+
+        // t(x)·(x^n - 1) is the numerator itself, checked away from the domain
+        for zeta in [1234, 98765, 4242424242] {
+            let zeta = FieldElement::new(zeta);
+            let vanishing = zeta.pow(n as u64) - FieldElement::one();
+            assert_eq!(
+                t.evaluate(zeta).multiply(&vanishing),
+                numerator.evaluate(zeta)
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Witness doesn't satisfy the circuit")]
+    fn test_compute_quotient_rejects_bad_witness() {
+        let mut circuit = cubic_circuit();
+        circuit.c[1] = FieldElement::new(28);
+        let prover = Prover::new(circuit, FieldElement::new(7), FieldElement::new(11));
+        prover.compute_quotient(FieldElement::new(13));
+    }
+
+    #[test]
+    fn test_linearization_polynomial() {
+        let circuit = cubic_circuit();
+        let n = circuit.size();
+        let prover = Prover::new(circuit, FieldElement::new(7), FieldElement::new(11));
+        let (alpha, zeta) = (FieldElement::new(13), FieldElement::new(1234));
+        let r = prover.linearization_polynomial(alpha, zeta);
+
+        // r(ζ) is the quotient identity at ζ computed from full evaluations
+        let t = prover.compute_quotient(alpha);
+        let expected = prover.quotient_numerator(alpha).evaluate(zeta)
+            - t.evaluate(zeta) * (zeta.pow(n as u64) - FieldElement::one());
+        assert_eq!(r.evaluate(zeta), expected);
+        assert_eq!(r.evaluate(zeta), FieldElement::zero());
+
+        // [r] only needs commitments to q_*, S_σ3, z and t, weighted by scalars
+        let kzg = KZG::new(3 * n + 3);
+        let [a, b, c] = prover.circuit.wire_polynomials().map(|w| w.evaluate(zeta));
+        let [q_m, q_l, q_r, q_o, q_c, q_range] = prover.circuit.selector_polynomials();
+        let [s1, s2, s3] = prover.circuit.sigma_polynomials();
+        let z = prover.permutation_polynomial();
+        let curve = EllipticCurve::new();
+        let weighted = |p: &Polynomial, w: FieldElement| kzg.commit(p).scalar_mul(w, &curve);
+        let (beta, gamma) = (prover.beta, prover.gamma);
+        let [k0, k1, k2] = Circuit::coset_shifts();
+        let omega = FieldElement::from_fr(prover.circuit.domain().group_gen());
+        let z_omega = z.evaluate(zeta * omega);
+        let pi = prover
+            .circuit
+            .public_input_polynomial(&prover.circuit.public_inputs)
+            .evaluate(zeta);
+        let l_0 = prover
+            .circuit
+            .interpolate(&[FieldElement::one()])
+            .evaluate(zeta);
+        let permuted = (a + beta * s1.evaluate(zeta) + gamma)
+            * (b + beta * s2.evaluate(zeta) + gamma)
+            * z_omega;
+        let z_weight = alpha
+            * (a + beta * k0 * zeta + gamma)
+            * (b + beta * k1 * zeta + gamma)
+            * (c + beta * k2 * zeta + gamma)
+            + alpha * alpha * l_0;
+        let constant_term = pi - alpha * permuted * (c + gamma) - alpha * alpha * l_0;
+        let vanishing = zeta.pow(n as u64) - FieldElement::one();
+        let r_commit = weighted(&q_m, a * b)
+            + weighted(&q_l, a)
+            + weighted(&q_r, b)
+            + weighted(&q_o, c)
+            + kzg.commit(&q_c)
+            + weighted(&q_range, b * (b - FieldElement::one()))
+            + weighted(&constant(FieldElement::one()), constant_term)
+            + weighted(&z, z_weight)
+            + weighted(&s3, (alpha * permuted * beta).negate())
+            + weighted(&t, vanishing.negate());
+        assert_eq!(kzg.commit(&r), r_commit);
+    }
+}
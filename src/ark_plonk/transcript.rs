@@ -0,0 +1,91 @@
+use super::elliptic_curve::Point;
+use super::field::FieldElement;
+use ark_bn254::Fr;
+use ark_ec::AffineRepr;
+use ark_ff::{BigInteger, PrimeField};
+use sha2::{Digest, Sha256};
+
+/*
+    Fiat-Shamir transcript: everything the verifier would have seen so far is
+    absorbed into a running SHA-256 state, and each challenge is read from a
+    hash of that state. Prover and verifier replay the same sequence of calls
+    and end up with the same challenges.
+*/
+
+#[derive(Clone)]
+pub struct Transcript {
+    state: Sha256,
+}
+
+impl Transcript {
+    #[allow(dead_code)]
+    pub fn new(label: &[u8]) -> Self {
+        let mut transcript = Transcript {
+            state: Sha256::new(),
+        };
+        transcript.append_message(b"domain-separator", label);
+        transcript
+    }
+
+    /// Absorbs label || len(data) || data, so that inputs can't shift into each other
+    fn append_message(&mut self, label: &[u8], data: &[u8]) {
+        self.state.update(label);
+        self.state.update((data.len() as u64).to_le_bytes());
+        self.state.update(data);
+    }
+
+    #[allow(dead_code)]
+    pub fn append_scalar(&mut self, label: &[u8], scalar: &FieldElement) {
+        self.append_message(label, &scalar.into_bigint().to_bytes_le());
+    }
+
+    /// Absorbs the affine coordinates, infinity as the empty string
+    #[allow(dead_code)]
+    pub fn append_point(&mut self, label: &[u8], point: &Point) {
+        let mut bytes = vec![];
+        if let Point::Affine(p) = point {
+            let (x, y) = p.xy().expect("Affine points aren't the identity");
+            bytes.extend(x.into_bigint().to_bytes_le());
+            bytes.extend(y.into_bigint().to_bytes_le());
+        }
+        self.append_message(label, &bytes);
+    }
+
+    /// Challenge reduced from a 256-bit hash of the transcript so far. The
+    /// challenge is absorbed back so the next one differs.
+    #[allow(dead_code)]
+    pub fn challenge(&mut self, label: &[u8]) -> FieldElement {
+        self.state.update(label);
+        let hash = self.state.clone().finalize();
+        let challenge = FieldElement::from_fr(Fr::from_le_bytes_mod_order(&hash));
+        self.append_scalar(label, &challenge);
+        challenge
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ark_plonk::elliptic_curve::EllipticCurve;
+
+    #[test]
+    fn test_transcript_is_deterministic() {
+        let curve = EllipticCurve::new();
+        let mut t1 = Transcript::new(b"test");
+        let mut t2 = Transcript::new(b"test");
+        for t in [&mut t1, &mut t2] {
+            t.append_point(b"g", &curve.generator());
+            t.append_scalar(b"x", &FieldElement::new(42));
+        }
+        let (beta, gamma) = (t1.challenge(b"beta"), t1.challenge(b"gamma"));
+        assert_eq!(beta, t2.challenge(b"beta"));
+        assert_eq!(gamma, t2.challenge(b"gamma"));
+        assert_ne!(beta, gamma);
+
+        // Any difference in the absorbed data changes the challenge
+        let mut t3 = Transcript::new(b"test");
+        t3.append_point(b"g", &curve.infinity());
+        t3.append_scalar(b"x", &FieldElement::new(42));
+        assert_ne!(t3.challenge(b"beta"), beta);
+    }
+}
@@ -0,0 +1,348 @@
+use super::circuit::Circuit;
+use super::elliptic_curve::{EllipticCurve, Point};
+use super::field::FieldElement;
+use super::kzg::KZG;
+use super::polynomial::evaluate_vanishing;
+use super::prover::{Proof, TRANSCRIPT_LABEL};
+use super::transcript::Transcript;
+use ark_bn254::Fr;
+use ark_poly::{EvaluationDomain, Radix2EvaluationDomain};
+
+/*
+    The verifier doesn't see the wires. It gets a VerifyingKey holding the
+    commitments to the selector and σ polynomials, and from the proof the
+    wire commitments and the evaluations ā, b̄, c̄, s̄_1, s̄_2, z̄_ω. With those
+    it builds the commitment to the linearization r(x) (see
+    `Prover::linearization_polynomial`) as a combination of commitments:
+
+        [r] = ā·b̄·[q_M] + ā·[q_L] + b̄·[q_R] + c̄·[q_O] + [q_C]
+            + b̄·(b̄ - 1)·[q_range] + (z-weight)·[z] - α·β·(σ-weight)·[S_σ3]
+            - (ζ^n - 1)·[t] + (constant)·[1]
+
+    r(ζ) = 0 is the quotient identity at ζ, so a single batched opening at ζ
+    of r, a, b, c, S_σ1, S_σ2 (r with claimed value 0) and the opening of z at
+    ζω are all that's left to check.
+*/
+
+/// The witness-free part of a circuit: its size, how many public inputs it
+/// takes and the commitments to its selector and σ polynomials
+#[derive(Clone, Debug)]
+pub struct VerifyingKey {
+    pub n: usize,
+    pub domain: Radix2EvaluationDomain<Fr>,
+    pub public_input_count: usize,
+    pub selector_commits: [Point; 6], // q_M, q_L, q_R, q_O, q_C, q_range
+    pub sigma_commits: [Point; 3],    // S_σ1, S_σ2, S_σ3
+}
+
+impl VerifyingKey {
+    /// Preprocesses the circuit. Only the gates and copy constraints are
+    /// read, the wire values don't end up in the key.
+    #[allow(dead_code)]
+    pub fn new(kzg: &KZG, circuit: &Circuit) -> Self {
+        VerifyingKey {
+            n: circuit.size(),
+            domain: circuit.domain(),
+            public_input_count: circuit.public_inputs.len(),
+            selector_commits: circuit.selector_polynomials().map(|q| kzg.commit(&q)),
+            sigma_commits: circuit.sigma_polynomials().map(|s| kzg.commit(&s)),
+        }
+    }
+
+    /// L_i(ζ) = ω^i·(ζ^n - 1) / (n·(ζ - ω^i)), the Lagrange basis of row i at ζ
+    fn lagrange_at(&self, i: usize, zeta: FieldElement) -> FieldElement {
+        let omega_i = FieldElement::from_fr(self.domain.element(i));
+        omega_i * evaluate_vanishing(self.n, &zeta)
+            / (FieldElement::new(self.n as u64) * (zeta - omega_i))
+    }
+
+    /// PI(ζ) = ∑_i -public[i]·L_i(ζ), without interpolating PI
+    fn public_input_eval(&self, public: &[FieldElement], zeta: FieldElement) -> FieldElement {
+        public
+            .iter()
+            .enumerate()
+            .fold(FieldElement::zero(), |acc, (i, x)| {
+                acc - *x * self.lagrange_at(i, zeta)
+            })
+    }
+}
+
+pub struct Verifier;
+
+impl Verifier {
+    #[allow(dead_code)]
+    pub fn verify(kzg: &KZG, vk: &VerifyingKey, public: &[FieldElement], proof: &Proof) -> bool {
+        if public.len() != vk.public_input_count {
+            return false;
+        }
+
+        // Replay the prover's transcript
+        let mut transcript = Transcript::new(TRANSCRIPT_LABEL);
+        for x in public {
+            transcript.append_scalar(b"public input", x);
+        }
+        transcript.append_point(b"a", &proof.a_commit);
+        transcript.append_point(b"b", &proof.b_commit);
+        transcript.append_point(b"c", &proof.c_commit);
+        let beta = transcript.challenge(b"beta");
+        let gamma = transcript.challenge(b"gamma");
+        transcript.append_point(b"z", &proof.z_commit);
+        let alpha = transcript.challenge(b"alpha");
+        transcript.append_point(b"t", &proof.t_commit);
+        let zeta = transcript.challenge(b"zeta");
+        for (label, eval) in [
+            (b"a_eval", &proof.a_eval),
+            (b"b_eval", &proof.b_eval),
+            (b"c_eval", &proof.c_eval),
+        ] {
+            transcript.append_scalar(label, eval);
+        }
+        transcript.append_scalar(b"s1_eval", &proof.s1_eval);
+        transcript.append_scalar(b"s2_eval", &proof.s2_eval);
+        transcript.append_scalar(b"z_omega_eval", &proof.z_omega_eval);
+        let v = transcript.challenge(b"v");
+
+        // [r] from the commitments, weighted by scalars known at ζ
+        let one = FieldElement::one();
+        let (a, b, c) = (proof.a_eval, proof.b_eval, proof.c_eval);
+        let (s1, s2) = (proof.s1_eval, proof.s2_eval);
+        let [k0, k1, k2] = Circuit::coset_shifts();
+        let l_0 = vk.lagrange_at(0, zeta);
+        let pi = vk.public_input_eval(public, zeta);
+        let permuted = (a + beta * s1 + gamma) * (b + beta * s2 + gamma) * proof.z_omega_eval;
+        let z_weight = alpha
+            * (a + beta * k0 * zeta + gamma)
+            * (b + beta * k1 * zeta + gamma)
+            * (c + beta * k2 * zeta + gamma)
+            + alpha * alpha * l_0;
+        let constant_term = pi - alpha * permuted * (c + gamma) - alpha * alpha * l_0;
+        let curve = EllipticCurve::new();
+        let [q_m, q_l, q_r, q_o, q_c, q_range] = &vk.selector_commits;
+        let r_commit = EllipticCurve::msm(
+            &[
+                q_m.clone(),
+                q_l.clone(),
+                q_r.clone(),
+                q_o.clone(),
+                q_c.clone(),
+                q_range.clone(),
+                curve.generator(),
+                proof.z_commit.clone(),
+                vk.sigma_commits[2].clone(),
+                proof.t_commit.clone(),
+            ],
+            &[
+                a * b,
+                a,
+                b,
+                c,
+                one,
+                b * (b - one),
+                constant_term,
+                z_weight,
+                (alpha * permuted * beta).negate(),
+                evaluate_vanishing(vk.n, &zeta).negate(),
+            ],
+        );
+
+        // Batched opening at ζ, r(ζ) = 0 standing for the quotient identity,
+        // and the opening of z at ζω
+        let mut combined_commit = Point::Infinity;
+        let mut combined_eval = FieldElement::zero();
+        let mut v_power = one;
+        for (commit, eval) in [
+            (&r_commit, FieldElement::zero()),
+            (&proof.a_commit, a),
+            (&proof.b_commit, b),
+            (&proof.c_commit, c),
+            (&vk.sigma_commits[0], s1),
+            (&vk.sigma_commits[1], s2),
+        ] {
+            combined_commit = combined_commit + commit.scalar_mul(v_power, &curve);
+            combined_eval = combined_eval + eval * v_power;
+            v_power = v_power * v;
+        }
+        let omega = FieldElement::from_fr(vk.domain.group_gen());
+        kzg.verify(&combined_commit, zeta, combined_eval, &proof.opening_proof)
+            && kzg.verify(
+                &proof.z_commit,
+                zeta * omega,
+                proof.z_omega_eval,
+                &proof.z_omega_proof,
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ark_plonk::circuit::{Column, Gate, WireRef};
+    use crate::ark_plonk::prover::Prover;
+
+    /// A single gate: 3·4 = 12
+    fn mul_circuit() -> Circuit {
+        let mut circuit = Circuit::new();
+        circuit.add_gate(
+            Gate::mul(),
+            FieldElement::new(3),
+            FieldElement::new(4),
+            FieldElement::new(12),
+        );
+        circuit
+    }
+
+    #[test]
+    fn test_prove_and_verify() {
+        let circuit = mul_circuit();
+        let kzg = KZG::new(3 * circuit.size() + 3);
+        let vk = VerifyingKey::new(&kzg, &circuit);
+        let proof = Prover::prove(&kzg, &circuit, &circuit.witness());
+        assert!(Verifier::verify(&kzg, &vk, &[], &proof));
+
+        // Wrong number of public inputs
+        assert!(!Verifier::verify(&kzg, &vk, &[FieldElement::one()], &proof));
+    }
+
+    #[test]
+    fn test_verifying_key_is_witness_free() {
+        // The same gates with every wire zeroed give the same key
+        let circuit = mul_circuit();
+        let kzg = KZG::new(3 * circuit.size() + 3);
+        let proof = Prover::prove(&kzg, &circuit, &circuit.witness());
+        let mut blank = circuit.witness();
+        for column in [&mut blank.a, &mut blank.b, &mut blank.c] {
+            column.fill(FieldElement::zero());
+        }
+        let vk = VerifyingKey::new(&kzg, &circuit.with_witness(&blank));
+        assert_eq!(
+            vk.selector_commits,
+            VerifyingKey::new(&kzg, &circuit).selector_commits
+        );
+        assert!(Verifier::verify(&kzg, &vk, &[], &proof));
+    }
+
+    #[test]
+    fn test_verify_rejects_tampering() {
+        let circuit = mul_circuit();
+        let kzg = KZG::new(3 * circuit.size() + 3);
+        let vk = VerifyingKey::new(&kzg, &circuit);
+
+        // 3·4 != 13
+        let mut witness = circuit.witness();
+        witness.c[0] = FieldElement::new(13);
+        let proof = Prover::prove(&kzg, &circuit, &witness);
+        assert!(!Verifier::verify(&kzg, &vk, &[], &proof));
+
+        // A valid proof with one wire evaluation changed
+        let mut proof = Prover::prove(&kzg, &circuit, &circuit.witness());
+        proof.a_eval = proof.a_eval + FieldElement::one();
+        assert!(!Verifier::verify(&kzg, &vk, &[], &proof));
+
+        // Or a σ evaluation, which only enters through [r]
+        let mut proof = Prover::prove(&kzg, &circuit, &circuit.witness());
+        proof.s1_eval = proof.s1_eval + FieldElement::one();
+        assert!(!Verifier::verify(&kzg, &vk, &[], &proof));
+    }
+
+    #[test]
+    fn test_verify_with_public_inputs() {
+        let mut circuit = Circuit::new();
+        circuit.add_public_input(FieldElement::new(12));
+        circuit.add_gate(
+            Gate::mul(),
+            FieldElement::new(3),
+            FieldElement::new(4),
+            FieldElement::new(12),
+        );
+        let kzg = KZG::new(3 * circuit.size() + 3);
+        let vk = VerifyingKey::new(&kzg, &circuit);
+        let proof = Prover::prove(&kzg, &circuit, &circuit.witness());
+        assert!(Verifier::verify(
+            &kzg,
+            &vk,
+            &[FieldElement::new(12)],
+            &proof
+        ));
+        assert!(!Verifier::verify(
+            &kzg,
+            &vk,
+            &[FieldElement::new(13)],
+            &proof
+        ));
+    }
+
+    #[test]
+    fn test_verify_enforces_copy_constraints() {
+        // 3·4 = 12, 12·2 = 24 with the first output wired into the second gate
+        let mut circuit = Circuit::new();
+        let (two, three, four) = (
+            FieldElement::new(2),
+            FieldElement::new(3),
+            FieldElement::new(4),
+        );
+        circuit.add_gate(Gate::mul(), three, four, FieldElement::new(12));
+        circuit.add_gate(
+            Gate::mul(),
+            FieldElement::new(12),
+            two,
+            FieldElement::new(24),
+        );
+        circuit.connect(WireRef::new(Column::C, 0), WireRef::new(Column::A, 1));
+        let kzg = KZG::new(3 * circuit.size() + 3);
+        let vk = VerifyingKey::new(&kzg, &circuit);
+
+        let proof = Prover::prove(&kzg, &circuit, &circuit.witness());
+        assert!(Verifier::verify(&kzg, &vk, &[], &proof));
+
+        // Both gates still hold, but the wired values differ: 3·4 = 12, 5·2 = 10
+        let mut witness = circuit.witness();
+        witness.a[1] = FieldElement::new(5);
+        witness.c[1] = FieldElement::new(10);
+        let proof = Prover::prove(&kzg, &circuit, &witness);
+        assert!(!Verifier::verify(&kzg, &vk, &[], &proof));
+    }
+
+    #[test]
+    fn test_verify_range_check() {
+        // Public 5 < 2^3
+        let mut circuit = Circuit::new();
+        let five = FieldElement::new(5);
+        let row = circuit.add_public_input(five);
+        let out = circuit.add_range_gate(five, 3);
+        circuit.connect(WireRef::new(Column::A, row), out);
+        let kzg = KZG::new(3 * circuit.size() + 3);
+        let vk = VerifyingKey::new(&kzg, &circuit);
+        let proof = Prover::prove(&kzg, &circuit, &circuit.witness());
+        assert!(Verifier::verify(&kzg, &vk, &[five], &proof));
+
+        // 9 doesn't fit in 3 bits: its low bits recompose to 1
+        let mut circuit = Circuit::new();
+        let nine = FieldElement::new(9);
+        let row = circuit.add_public_input(nine);
+        let out = circuit.add_range_gate(nine, 3);
+        circuit.connect(WireRef::new(Column::A, row), out);
+        let vk = VerifyingKey::new(&kzg, &circuit);
+        let proof = Prover::prove(&kzg, &circuit, &circuit.witness());
+        assert!(!Verifier::verify(&kzg, &vk, &[nine], &proof));
+    }
+
+    #[test]
+    fn test_proof_serialization_round_trip() {
+        let circuit = mul_circuit();
+        let kzg = KZG::new(3 * circuit.size() + 3);
+        let vk = VerifyingKey::new(&kzg, &circuit);
+        let proof = Prover::prove(&kzg, &circuit, &circuit.witness());
+        let bytes = proof.to_bytes();
+        // 7 compressed G1 points and 6 scalars, 32 bytes each
+        assert_eq!(bytes.len(), 13 * 32);
+
+        let decoded = Proof::from_bytes(&bytes).unwrap();
+        assert_eq!(decoded, proof);
+        assert!(Verifier::verify(&kzg, &vk, &[], &decoded));
+
+        assert!(Proof::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+        assert!(Proof::from_bytes(&trailing).is_err());
+    }
+}
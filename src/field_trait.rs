@@ -0,0 +1,21 @@
+use std::fmt::Debug;
+
+/// Common interface of the field backends (`my_plonk` over F_101 and
+/// `ark_plonk` over the BN254 scalar field), so polynomial code can be
+/// written once and instantiated over either of them.
+pub trait Field: Clone + PartialEq + Debug {
+    fn zero() -> Self;
+    fn one() -> Self;
+    fn add(&self, other: &Self) -> Self;
+    fn sub(&self, other: &Self) -> Self;
+    fn mul(&self, other: &Self) -> Self;
+    fn inverse(&self) -> Self;
+
+    fn is_zero(&self) -> bool {
+        *self == Self::zero()
+    }
+
+    fn negate(&self) -> Self {
+        Self::zero().sub(self)
+    }
+}
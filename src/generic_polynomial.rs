@@ -0,0 +1,180 @@
+use crate::field_trait::Field;
+
+use std::ops::{Add, Mul, Sub};
+
+/// Polynomial over any `Field` backend. Same convention as the backend
+/// specific polynomials: [1, 2, 3] represents y = 1 + 2x + 3x^2.
+/// Trailing zeros are always trimmed, so the zero polynomial has no coefficients.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polynomial<F: Field> {
+    pub coeffs: Vec<F>,
+}
+
+impl<F: Field> Polynomial<F> {
+    pub fn new(coeffs: Vec<F>) -> Self {
+        let mut p = Polynomial { coeffs };
+        p.trim();
+        p
+    }
+
+    fn trim(&mut self) {
+        while self.coeffs.last().is_some_and(|c| c.is_zero()) {
+            self.coeffs.pop();
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn degree(&self) -> Option<usize> {
+        self.coeffs.len().checked_sub(1)
+    }
+
+    #[allow(dead_code)]
+    pub fn evaluate(&self, x: &F) -> F {
+        self.coeffs
+            .iter()
+            .rev()
+            .fold(F::zero(), |acc, coeff| acc.mul(x).add(coeff))
+    }
+
+    #[allow(dead_code)]
+    pub fn scalar_mul(&self, scalar: &F) -> Self {
+        Polynomial::new(self.coeffs.iter().map(|c| c.mul(scalar)).collect())
+    }
+
+    /// Long division, returns (quotient, remainder)
+    #[allow(dead_code)]
+    pub fn divide(&self, divisor: &Self) -> (Self, Self) {
+        let divisor_deg = divisor.degree().expect("Cannot divide by zero polynomial");
+        let inv_divisor_lead = divisor.coeffs[divisor_deg].inverse();
+
+        let mut remainder = self.coeffs.clone();
+        if remainder.len() <= divisor_deg {
+            return (Polynomial::new(vec![]), Polynomial::new(remainder));
+        }
+
+        let mut quotient = vec![F::zero(); remainder.len() - divisor_deg];
+        for i in (0..quotient.len()).rev() {
+            let term = remainder[i + divisor_deg].mul(&inv_divisor_lead);
+            for (j, d) in divisor.coeffs.iter().enumerate() {
+                remainder[i + j] = remainder[i + j].sub(&d.mul(&term));
+            }
+            quotient[i] = term;
+        }
+
+        (Polynomial::new(quotient), Polynomial::new(remainder))
+    }
+
+    /// P(x) = ∑_i y_i ⋅ l_i(x)
+    /// where l_i(x)= ∏_j≠i (x−xj)/(xi−xj)
+    #[allow(dead_code)]
+    pub fn lagrange_interpolate(points: &[(F, F)]) -> Self {
+        assert!(!points.is_empty(), "Need at least one point");
+        let mut result = Polynomial::new(vec![]);
+
+        for (i, (xi, yi)) in points.iter().enumerate() {
+            let mut term = Polynomial::new(vec![F::one()]);
+            let mut denominator = F::one();
+
+            for (j, (xj, _)) in points.iter().enumerate() {
+                if i != j {
+                    term = term * Polynomial::new(vec![xj.negate(), F::one()]);
+                    denominator = denominator.mul(&xi.sub(xj));
+                }
+            }
+
+            result = result + term.scalar_mul(&yi.mul(&denominator.inverse()));
+        }
+
+        result
+    }
+}
+
+impl<F: Field> Add for Polynomial<F> {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        let max_len = self.coeffs.len().max(other.coeffs.len());
+        let zero = F::zero();
+        let result = (0..max_len)
+            .map(|i| {
+                let a = self.coeffs.get(i).unwrap_or(&zero);
+                let b = other.coeffs.get(i).unwrap_or(&zero);
+                a.add(b)
+            })
+            .collect();
+        Polynomial::new(result)
+    }
+}
+
+impl<F: Field> Sub for Polynomial<F> {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        let max_len = self.coeffs.len().max(other.coeffs.len());
+        let zero = F::zero();
+        let result = (0..max_len)
+            .map(|i| {
+                let a = self.coeffs.get(i).unwrap_or(&zero);
+                let b = other.coeffs.get(i).unwrap_or(&zero);
+                a.sub(b)
+            })
+            .collect();
+        Polynomial::new(result)
+    }
+}
+
+impl<F: Field> Mul for Polynomial<F> {
+    type Output = Self;
+    fn mul(self, other: Self) -> Self {
+        if self.coeffs.is_empty() || other.coeffs.is_empty() {
+            return Polynomial::new(vec![]);
+        }
+        let mut result = vec![F::zero(); self.coeffs.len() + other.coeffs.len() - 1];
+        for (i, a) in self.coeffs.iter().enumerate() {
+            for (j, b) in other.coeffs.iter().enumerate() {
+                result[i + j] = result[i + j].add(&a.mul(b));
+            }
+        }
+        Polynomial::new(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ark_plonk::field::FieldElement as ArkFieldElement;
+    use crate::my_plonk::field::FieldElement as MyFieldElement;
+
+    /// n as a field element, built from `one` so it works for any backend
+    fn constant<F: Field>(n: u64) -> F {
+        (0..n).fold(F::zero(), |acc, _| acc.add(&F::one()))
+    }
+
+    fn check_divide_and_interpolate<F: Field>() {
+        // P(x) = 1 + 2x + 3x^2, D(x) = 2 + x
+        let p = Polynomial::new(vec![constant::<F>(1), constant(2), constant(3)]);
+        let d = Polynomial::new(vec![constant::<F>(2), F::one()]);
+        let (q, r) = p.divide(&d);
+        assert_eq!(q.coeffs, vec![constant::<F>(4).negate(), constant(3)]);
+        assert_eq!(r.coeffs, vec![constant::<F>(9)]);
+        assert_eq!(q * d + r, p);
+
+        // Exact division leaves the zero polynomial, which has no coefficients
+        let (_, r) = p.divide(&p);
+        assert_eq!(r.coeffs, vec![]);
+
+        let points = vec![
+            (constant::<F>(0), constant::<F>(1)),
+            (constant(1), constant(2)),
+            (constant(2), constant(4)),
+        ];
+        let interpolated = Polynomial::lagrange_interpolate(&points);
+        for (x, y) in &points {
+            assert_eq!(&interpolated.evaluate(x), y);
+        }
+    }
+
+    #[test]
+    fn test_generic_polynomial() {
+        check_divide_and_interpolate::<MyFieldElement>();
+        check_divide_and_interpolate::<ArkFieldElement>();
+    }
+}
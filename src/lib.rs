@@ -3,3 +3,7 @@ mod my_plonk;
 
 /// MATH WITH ARKWORKS MODULES
 mod ark_plonk;
+
+/// FIELD-GENERIC POLYNOMIALS
+mod field_trait;
+mod generic_polynomial;
@@ -1,6 +1,9 @@
+mod domain;
 mod elliptic_curve;
 mod ext_euclidean_algo;
-mod field;
+pub(crate) mod field;
+mod hash;
 mod kzg;
+mod pedersen;
 mod polynomial;
 mod toy_pairing;
@@ -0,0 +1,168 @@
+use super::field::FieldElement;
+use super::polynomial::Polynomial;
+
+/*
+    Evaluation domain H = {ω^0, ω^1, ..., ω^(n-1)}: the multiplicative
+    subgroup of F_101^* of size n, where ω is a primitive n-th root of unity.
+    F_101^* is cyclic of order 100, so only sizes dividing 100 exist
+    (1, 2, 4, 5, 10, 20, 25, 50, 100).
+*/
+#[derive(Clone, Debug, PartialEq)]
+pub struct EvaluationDomain {
+    pub size: usize,
+    pub generator: FieldElement, // ω
+    pub elements: Vec<FieldElement>,
+}
+
+impl EvaluationDomain {
+    /// Builds the domain of the given size, None if size doesn't divide 100
+    #[allow(dead_code)]
+    pub fn new(size: usize) -> Option<Self> {
+        let elements = FieldElement::roots_of_unity(size as u64)?;
+        Some(EvaluationDomain {
+            size,
+            generator: elements[1 % size].clone(),
+            elements,
+        })
+    }
+
+    /// Domain of size 2^log_n, the shape radix-2 FFTs need. F_101^* has order
+    /// 100 = 4·25, so only 1, 2 and 4 exist here and anything bigger is None.
+    /// Larger power-of-two domains need a prime with p - 1 divisible by a high
+    /// power of two, e.g. 97 = 2^5·3 + 1 or 257 = 2^8 + 1.
+    #[allow(dead_code)]
+    pub fn power_of_two(log_n: u32) -> Option<Self> {
+        let size = 1usize.checked_shl(log_n)?;
+        Self::new(size)
+    }
+
+    /// ω^i
+    #[allow(dead_code)]
+    pub fn element(&self, i: usize) -> FieldElement {
+        self.elements[i % self.size].clone()
+    }
+
+    /// L_i(x) = (ω^i / n) · (x^n - 1) / (x - ω^i)
+    /// equals 1 at ω^i and 0 at every other element of the domain
+    #[allow(dead_code)]
+    pub fn lagrange_basis(&self, i: usize) -> Polynomial {
+        let omega_i = self.element(i);
+        let (quotient, _) =
+            Polynomial::vanishing_polynomial(self.size).divide(&Polynomial::new(vec![
+                omega_i.negate(),
+                FieldElement::one(),
+            ]));
+        quotient.scalar_mul(omega_i.divide(&FieldElement::new(self.size as u64)))
+    }
+
+    /// Evaluates at zeta the polynomial taking evals[i] at ω^i, without
+    /// interpolating it:
+    ///   p(zeta) = (zeta^n - 1) / n · ∑ evals[i]·ω^i / (zeta - ω^i)
+    /// The formula divides by zero on the domain, where p(ω^i) = evals[i].
+    #[allow(dead_code)]
+    pub fn barycentric_eval(&self, evals: &[FieldElement], zeta: FieldElement) -> FieldElement {
+        assert_eq!(
+            evals.len(),
+            self.size,
+            "Expected one evaluation per domain element"
+        );
+        if let Some(i) = self.elements.iter().position(|x| *x == zeta) {
+            return evals[i].clone();
+        }
+        let mut sum = FieldElement::zero();
+        for (eval, omega_i) in evals.iter().zip(&self.elements) {
+            let term = eval.multiply(omega_i).divide(&zeta.substract(omega_i));
+            sum = FieldElement::add(&sum, &term);
+        }
+        let vanishing = zeta.pow(self.size as u64).substract(&FieldElement::one());
+        vanishing
+            .divide(&FieldElement::new(self.size as u64))
+            .multiply(&sum)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_domain_elements() {
+        let domain = EvaluationDomain::new(4).unwrap();
+        assert_eq!(domain.generator, FieldElement::new(10)); // 2^25 mod 101
+        assert_eq!(
+            domain.elements,
+            vec![
+                FieldElement::new(1),
+                FieldElement::new(10),
+                FieldElement::new(100),
+                FieldElement::new(91),
+            ]
+        );
+        assert_eq!(domain.generator.pow(4), FieldElement::one());
+        assert_eq!(domain.element(5), domain.element(1));
+    }
+
+    #[test]
+    fn test_domain_invalid_size() {
+        assert!(EvaluationDomain::new(0).is_none());
+        assert!(EvaluationDomain::new(3).is_none());
+        assert!(EvaluationDomain::new(8).is_none());
+        assert!(EvaluationDomain::new(100).is_some());
+    }
+
+    #[test]
+    fn test_power_of_two_domain() {
+        for (log_n, size) in [(0, 1), (1, 2), (2, 4)] {
+            let domain = EvaluationDomain::power_of_two(log_n).unwrap();
+            assert_eq!(domain, EvaluationDomain::new(size).unwrap());
+        }
+        assert!(EvaluationDomain::power_of_two(3).is_none());
+        assert!(EvaluationDomain::power_of_two(64).is_none());
+    }
+
+    #[test]
+    fn test_lagrange_basis() {
+        let domain = EvaluationDomain::new(4).unwrap();
+        for i in 0..domain.size {
+            let l_i = domain.lagrange_basis(i);
+            assert_eq!(l_i.degree(), domain.size - 1);
+            for (j, x) in domain.elements.iter().enumerate() {
+                let expected = if i == j {
+                    FieldElement::one()
+                } else {
+                    FieldElement::zero()
+                };
+                assert_eq!(l_i.evaluate(x.clone()), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_barycentric_eval() {
+        use ark_std::rand::{rngs::StdRng, SeedableRng};
+        let mut rng = StdRng::seed_from_u64(1904);
+        for size in [1, 4, 5, 20] {
+            let domain = EvaluationDomain::new(size).unwrap();
+            for _ in 0..10 {
+                let evals: Vec<FieldElement> =
+                    (0..size).map(|_| FieldElement::rand(&mut rng)).collect();
+                let interpolated = evals
+                    .iter()
+                    .enumerate()
+                    .map(|(i, e)| domain.lagrange_basis(i).scalar_mul(e.clone()))
+                    .sum::<Polynomial>();
+                let zeta = FieldElement::rand(&mut rng);
+                assert_eq!(
+                    domain.barycentric_eval(&evals, zeta.clone()),
+                    interpolated.evaluate(zeta)
+                );
+            }
+            // On the domain it returns the evaluation itself
+            let evals: Vec<FieldElement> = (0..size as u64).map(FieldElement::new).collect();
+            assert_eq!(
+                domain.barycentric_eval(&evals, domain.element(size - 1)),
+                evals[size - 1]
+            );
+        }
+    }
+}
@@ -1,4 +1,6 @@
-use super::field::{FieldElement, FieldElementExt};
+use super::field::{FieldElement, FieldElementExt, Scalar};
+use super::hash::hash_to_field;
+use ark_std::rand::Rng;
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct Point {
@@ -14,14 +16,47 @@ pub struct PointExt {
     pub is_infinity: bool,
 }
 
+/// Jacobian coordinates: (X, Y, Z) stands for the affine point (X/Z^2, Y/Z^3)
+/// and Z = 0 is the point at infinity. Additions and doublings need no field
+/// inversion, only the final conversion back to affine does.
+#[derive(Clone, Debug)]
+pub struct JacobianPoint {
+    pub x: FieldElement,
+    pub y: FieldElement,
+    pub z: FieldElement,
+}
+
 #[allow(dead_code)]
 #[derive(Clone, Debug)]
 pub struct EllipticCurve {
     a: FieldElement,
     b: FieldElement,
-    g1: Point,    // Generator for G1
-    g2: PointExt, // Generator for G2
-    order: u64,   // Subgroup order
+    g1: Point,     // Generator for G1
+    g2: PointExt,  // Generator for G2
+    order: u64,    // Subgroup order
+    cofactor: u64, // Number of curve points divided by the subgroup order
+}
+
+/// Fixed-base table of generator multiples: `windows[j][d] = [d·2^(w·j)]G`.
+/// A scalar multiplication becomes one lookup per window plus the additions.
+#[derive(Clone, Debug)]
+pub struct GeneratorTable {
+    curve: EllipticCurve,
+    window: usize,
+    windows: Vec<Vec<Point>>,
+}
+
+impl GeneratorTable {
+    #[allow(dead_code)]
+    pub fn mul(&self, scalar: Scalar) -> Point {
+        let mask = (1u64 << self.window) - 1;
+        let mut result = Point::infinity();
+        for (j, table) in self.windows.iter().enumerate() {
+            let digit = (scalar.value >> (self.window * j)) & mask;
+            self.curve.add_assign(&mut result, &table[digit as usize]);
+        }
+        result
+    }
 }
 
 impl Point {
@@ -34,19 +69,205 @@ impl Point {
         }
     }
 
+    /// Affine coordinates, None for the point at infinity (its x and y are
+    /// just placeholders and must not be read as coordinates)
     #[allow(dead_code)]
-    pub fn scalar_mul(&self, curve: &EllipticCurve, scalar: FieldElement) -> Point {
-        let mut result = Point::infinity();
-        let mut temp = self.clone();
-        let mut s = scalar.value;
+    pub fn xy(&self) -> Option<(FieldElement, FieldElement)> {
+        if self.is_infinity {
+            None
+        } else {
+            Some((self.x.clone(), self.y.clone()))
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn scalar_mul(&self, curve: &EllipticCurve, scalar: Scalar) -> Point {
+        self.double_and_add(curve, scalar.value)
+    }
+
+    /// Scalar multiplication by a raw integer, reduced modulo the subgroup order.
+    ///
+    /// Note: a `FieldElement` is reduced mod 101 (the base field), which is
+    /// the wrong modulus for group operations. Points in the subgroup satisfy
+    /// [17]P = O, so scalars must be reduced mod 17 instead (see `Scalar`).
+    #[allow(dead_code)]
+    pub fn scalar_mul_u64(&self, curve: &EllipticCurve, scalar: u64) -> Point {
+        self.double_and_add(curve, scalar % curve.order)
+    }
+
+    /// Scalar multiplication by a little-endian byte string of any length,
+    /// reduced modulo the subgroup order. The empty string is zero.
+    #[allow(dead_code)]
+    pub fn scalar_mul_bytes(&self, curve: &EllipticCurve, bytes: &[u8]) -> Point {
+        // Horner from the most significant byte, reducing at every step
+        let scalar = bytes
+            .iter()
+            .rev()
+            .fold(0, |acc, &byte| (acc * 256 + byte as u64) % curve.order);
+        self.double_and_add(curve, scalar)
+    }
+
+    /// -P = (x, -y), infinity is its own negation
+    #[allow(dead_code)]
+    pub fn negate(&self) -> Point {
+        if self.is_infinity {
+            return self.clone();
+        }
+        Point {
+            x: self.x.clone(),
+            y: self.y.negate(),
+            is_infinity: false,
+        }
+    }
+
+    /// [k]P for a signed k, computed as [|k|](-P) when k is negative
+    #[allow(dead_code)]
+    pub fn scalar_mul_signed(&self, curve: &EllipticCurve, scalar: i64) -> Point {
+        if scalar < 0 {
+            self.negate().scalar_mul_u64(curve, scalar.unsigned_abs())
+        } else {
+            self.scalar_mul_u64(curve, scalar as u64)
+        }
+    }
+
+    /// Runs in Jacobian coordinates, converting back to affine only once
+    fn double_and_add(&self, curve: &EllipticCurve, scalar: u64) -> Point {
+        let mut result = JacobianPoint::infinity();
+        let mut temp = JacobianPoint::from_affine(self);
+        let mut s = scalar;
         while s > 0 {
             if s & 1 == 1 {
-                result = curve.add(&result, &temp);
+                result.add_assign(&temp, curve);
             }
-            temp = curve.add(&temp, &temp);
+            temp = temp.double(curve);
             s >>= 1;
         }
-        result
+        result.to_affine()
+    }
+}
+
+impl JacobianPoint {
+    #[allow(dead_code)]
+    pub fn infinity() -> Self {
+        JacobianPoint {
+            x: FieldElement::one(),
+            y: FieldElement::one(),
+            z: FieldElement::zero(),
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn is_infinity(&self) -> bool {
+        self.z == FieldElement::zero()
+    }
+
+    /// (x, y) -> (x, y, 1)
+    #[allow(dead_code)]
+    pub fn from_affine(p: &Point) -> Self {
+        if p.is_infinity {
+            return Self::infinity();
+        }
+        JacobianPoint {
+            x: p.x.clone(),
+            y: p.y.clone(),
+            z: FieldElement::one(),
+        }
+    }
+
+    /// (X, Y, Z) -> (X/Z^2, Y/Z^3), the only inversion
+    #[allow(dead_code)]
+    pub fn to_affine(&self) -> Point {
+        if self.is_infinity() {
+            return Point::infinity();
+        }
+        let z_inv = self.z.inverse();
+        let z_inv_2 = z_inv.multiply(&z_inv);
+        Point {
+            x: self.x.multiply(&z_inv_2),
+            y: self.y.multiply(&z_inv_2).multiply(&z_inv),
+            is_infinity: false,
+        }
+    }
+
+    /// dbl-2007-bl style doubling for y^2 = x^3 + ax + b
+    #[allow(dead_code)]
+    pub fn double(&self, curve: &EllipticCurve) -> Self {
+        if self.is_infinity() || self.y == FieldElement::zero() {
+            return Self::infinity();
+        }
+        let xx = self.x.multiply(&self.x);
+        let yy = self.y.multiply(&self.y);
+        let zz = self.z.multiply(&self.z);
+        // S = 4·X·Y^2, M = 3·X^2 + a·Z^4
+        let s = self.x.multiply(&yy).multiply(&FieldElement::new(4));
+        let m = xx
+            .multiply(&FieldElement::new(3))
+            .add(&curve.a.multiply(&zz).multiply(&zz));
+
+        let x3 = m.multiply(&m).substract(&s).substract(&s);
+        let y3 = m
+            .multiply(&s.substract(&x3))
+            .substract(&yy.multiply(&yy).multiply(&FieldElement::new(8)));
+        let z3 = self.y.multiply(&self.z).multiply(&FieldElement::new(2));
+        JacobianPoint {
+            x: x3,
+            y: y3,
+            z: z3,
+        }
+    }
+
+    /// add-1998-cmo-2 style addition, falls back to doubling for equal points
+    #[allow(dead_code)]
+    pub fn add(&self, other: &Self, curve: &EllipticCurve) -> Self {
+        let mut sum = self.clone();
+        sum.add_assign(other, curve);
+        sum
+    }
+
+    /// self += other, writing the sum into self's coordinates. This is the
+    /// accumulator update of `scalar_mul`, like `EllipticCurve::add_assign`
+    /// for affine points.
+    #[allow(dead_code)]
+    pub fn add_assign(&mut self, other: &Self, curve: &EllipticCurve) {
+        if other.is_infinity() {
+            return;
+        }
+        if self.is_infinity() {
+            self.clone_from(other);
+            return;
+        }
+        let z1z1 = self.z.multiply(&self.z);
+        let z2z2 = other.z.multiply(&other.z);
+        let u1 = self.x.multiply(&z2z2);
+        let u2 = other.x.multiply(&z1z1);
+        let s1 = self.y.multiply(&other.z).multiply(&z2z2);
+        let s2 = other.y.multiply(&self.z).multiply(&z1z1);
+        if u1 == u2 {
+            *self = if s1 == s2 {
+                self.double(curve)
+            } else {
+                Self::infinity() // P + (-P)
+            };
+            return;
+        }
+
+        let h = u2.substract(&u1);
+        let r = s2.substract(&s1);
+        let hh = h.multiply(&h);
+        let hhh = hh.multiply(&h);
+        let u1_hh = u1.multiply(&hh);
+
+        let x3 = r
+            .multiply(&r)
+            .substract(&hhh)
+            .substract(&u1_hh)
+            .substract(&u1_hh);
+        let y3 = r
+            .multiply(&u1_hh.substract(&x3))
+            .substract(&s1.multiply(&hhh));
+        self.z = self.z.multiply(&other.z).multiply(&h);
+        self.x = x3;
+        self.y = y3;
     }
 }
 
@@ -60,11 +281,25 @@ impl PointExt {
         }
     }
 
+    /// Embeds a point of E(F_101) into E(F_101^2), imaginary parts zero
+    #[allow(dead_code)]
+    pub fn from_base(p: &Point) -> PointExt {
+        PointExt {
+            x: FieldElementExt::from_base(p.x.clone()),
+            y: FieldElementExt::from_base(p.y.clone()),
+            is_infinity: p.is_infinity,
+        }
+    }
+
     #[allow(dead_code)]
-    pub fn scalar_mul(&self, curve: &EllipticCurve, scalar: FieldElement) -> PointExt {
+    pub fn scalar_mul(&self, curve: &EllipticCurve, scalar: Scalar) -> PointExt {
+        self.double_and_add(curve, scalar.value)
+    }
+
+    fn double_and_add(&self, curve: &EllipticCurve, scalar: u64) -> PointExt {
         let mut result = PointExt::infinity();
         let mut temp = self.clone();
-        let mut s = scalar.value;
+        let mut s = scalar;
         while s > 0 {
             if s & 1 == 1 {
                 result = curve.add_ext(&result, &temp);
@@ -76,7 +311,65 @@ impl PointExt {
     }
 }
 
+#[derive(Debug, PartialEq)]
+pub enum CurveError {
+    SingularCurve,       // 4a^3 + 27b^2 == 0
+    GeneratorNotOnCurve, // g1 doesn't satisfy y^2 = x^3 + ax + b
+    GeneratorWrongOrder, // g1 doesn't have the given order
+    G2NotOnCurve,        // g2 isn't a point of the curve over F_101^2
+    G2WrongOrder,        // [order]g2 isn't the point at infinity
+}
+
 impl EllipticCurve {
+    /// Custom toy curve y^2 = x^3 + ax + b over F_101. The order of g1 is
+    /// derived by counting its multiples and has to match the given one, and
+    /// g2 must be a curve point over F_101^2 with [order]g2 = O. The points are
+    /// counted to get the cofactor cleared by `hash_to_curve` and `random_point`.
+    /// `Scalar` is fixed to F_17, so only `scalar_mul_u64` and friends reduce by
+    /// another order.
+    #[allow(dead_code)]
+    pub fn with_params(
+        a: FieldElement,
+        b: FieldElement,
+        g1: Point,
+        g2: PointExt,
+        order: u64,
+    ) -> Result<Self, CurveError> {
+        let discriminant = FieldElement::new(4)
+            .multiply(&a.pow(3))
+            .add(&FieldElement::new(27).multiply(&b.pow(2)));
+        if discriminant == FieldElement::zero() {
+            return Err(CurveError::SingularCurve);
+        }
+        let mut curve = EllipticCurve {
+            a,
+            b,
+            g1,
+            g2,
+            order: 1,
+            cofactor: 1,
+        };
+        if curve.g1.is_infinity || !curve.is_on_curve(&curve.g1) {
+            return Err(CurveError::GeneratorNotOnCurve);
+        }
+        // point_order searches up to order·cofactor, the whole group for now
+        let point_count = curve.point_count();
+        curve.order = point_count;
+        if curve.point_order(&curve.g1) != order {
+            return Err(CurveError::GeneratorWrongOrder);
+        }
+        if curve.g2.is_infinity || !curve.is_on_curve_ext(&curve.g2) {
+            return Err(CurveError::G2NotOnCurve);
+        }
+        if !curve.g2.double_and_add(&curve, order).is_infinity {
+            return Err(CurveError::G2WrongOrder);
+        }
+        // The order of g1 divides the number of points
+        curve.order = order;
+        curve.cofactor = point_count / order;
+        Ok(curve)
+    }
+
     #[allow(dead_code)]
     pub fn new() -> Self {
         let a = FieldElement::new(0); // y^2 = x^3 + 0x + 3
@@ -96,7 +389,8 @@ impl EllipticCurve {
             b,
             g1,
             g2,
-            order: 17, // there are 17 valid points generated from (1, 2)
+            order: 17,   // there are 17 valid points generated from (1, 2)
+            cofactor: 6, // 102 points on the curve
         }
     }
 
@@ -110,6 +404,32 @@ impl EllipticCurve {
         self.g2.clone()
     }
 
+    #[allow(dead_code)]
+    pub fn a(&self) -> FieldElement {
+        self.a.clone()
+    }
+
+    #[allow(dead_code)]
+    pub fn order(&self) -> u64 {
+        self.order
+    }
+
+    #[allow(dead_code)]
+    pub fn cofactor(&self) -> u64 {
+        self.cofactor
+    }
+
+    /// Number of points on the curve, infinity included: 1 plus, for every x,
+    /// 2 if x^3 + ax + b is a nonzero square or 1 if it's zero
+    #[allow(dead_code)]
+    pub fn point_count(&self) -> u64 {
+        let mut count = 1;
+        for x in 0..FieldElement::MODULUS {
+            count += (self.curve_rhs(&FieldElement::new(x)).legendre() + 1) as u64;
+        }
+        count
+    }
+
     #[allow(dead_code)]
     pub fn infinity(&self) -> Point {
         Point::infinity()
@@ -120,6 +440,66 @@ impl EllipticCurve {
         PointExt::infinity()
     }
 
+    /// Precomputes the multiples of G1 needed for windowed fixed-base
+    /// scalar multiplication, covering every scalar below the subgroup order.
+    #[allow(dead_code)]
+    pub fn precompute_generator_table(&self, window: usize) -> GeneratorTable {
+        assert!(window > 0, "Window size must be at least one bit");
+        let scalar_bits = (u64::BITS - (self.order - 1).leading_zeros()) as usize;
+        let num_windows = scalar_bits.div_ceil(window);
+
+        let mut windows = Vec::with_capacity(num_windows);
+        let mut base = self.g1.clone(); // [2^(w·j)]G
+        for _ in 0..num_windows {
+            let mut table = vec![Point::infinity()];
+            for d in 1..(1usize << window) {
+                table.push(self.add(&table[d - 1], &base));
+            }
+            for _ in 0..window {
+                base = self.add(&base, &base);
+            }
+            windows.push(table);
+        }
+
+        GeneratorTable {
+            curve: self.clone(),
+            window,
+            windows,
+        }
+    }
+
+    /// y^2 == x^3 + ax + b (the point at infinity is always on the curve)
+    /// Same check as `is_on_curve` for a point over F_101^2
+    #[allow(dead_code)]
+    pub fn is_on_curve_ext(&self, p: &PointExt) -> bool {
+        if p.is_infinity {
+            return true;
+        }
+        let a = p.x.with_parts(self.a.clone(), FieldElement::zero());
+        let b = p.x.with_parts(self.b.clone(), FieldElement::zero());
+        let lhs = p.y.multiply(&p.y);
+        let rhs =
+            p.x.multiply(&p.x)
+                .multiply(&p.x)
+                .add(&a.multiply(&p.x))
+                .add(&b);
+        lhs == rhs
+    }
+
+    #[allow(dead_code)]
+    pub fn is_on_curve(&self, p: &Point) -> bool {
+        if p.is_infinity {
+            return true;
+        }
+        let lhs = p.y.multiply(&p.y);
+        let rhs =
+            p.x.multiply(&p.x)
+                .multiply(&p.x)
+                .add(&self.a.multiply(&p.x))
+                .add(&self.b);
+        lhs == rhs
+    }
+
     pub fn add(&self, p1: &Point, p2: &Point) -> Point {
         if p1.is_infinity {
             return p2.clone();
@@ -130,19 +510,13 @@ impl EllipticCurve {
         if p1.x == p2.x && p1.y != p2.y {
             return Point::infinity();
         }
+        if p1 == p2 {
+            return self.double(p1);
+        }
 
-        let m: FieldElement = if p1 == p2 {
-            let num: FieldElement =
-                p1.x.multiply(&p1.x)
-                    .multiply(&FieldElement::new(3))
-                    .add(&self.a);
-            let den = p1.y.multiply(&FieldElement::new(2));
-            num.divide(&den)
-        } else {
-            let num: FieldElement = p2.y.substract(&p1.y);
-            let den: FieldElement = p2.x.substract(&p1.x);
-            num.divide(&den)
-        };
+        let num: FieldElement = p2.y.substract(&p1.y);
+        let den: FieldElement = p2.x.substract(&p1.x);
+        let m: FieldElement = num.divide(&den);
 
         let x3: FieldElement = m.multiply(&m).substract(&p1.x).substract(&p2.x);
         let y3: FieldElement = m.multiply(&p1.x.substract(&x3)).substract(&p1.y);
@@ -153,6 +527,200 @@ impl EllipticCurve {
         }
     }
 
+    /// [base, [tau]base, [tau^2]base, ..., [tau^up_to]base], each point being
+    /// [tau] times the previous one instead of recomputing tau^n from scratch
+    #[allow(dead_code)]
+    pub fn scalar_mul_powers(&self, base: &Point, tau: FieldElement, up_to: usize) -> Vec<Point> {
+        let tau = Scalar::from_field_element(&tau);
+        let mut powers = Vec::with_capacity(up_to + 1);
+        let mut current = base.clone();
+        for _ in 0..up_to {
+            let next = current.scalar_mul(self, tau.clone());
+            powers.push(current);
+            current = next;
+        }
+        powers.push(current);
+        powers
+    }
+
+    /// Smallest k > 0 with [k]P == O, found by adding P to itself. Points of the
+    /// subgroup have order 1 or 17, other points of the curve divide the
+    /// number of points, order·cofactor.
+    #[allow(dead_code)]
+    pub fn point_order(&self, p: &Point) -> u64 {
+        let group_order = self.order * self.cofactor;
+        let mut multiple = p.clone();
+        for k in 1..=group_order {
+            if multiple.is_infinity {
+                return k;
+            }
+            self.add_assign(&mut multiple, p);
+        }
+        panic!("Point is not on the curve");
+    }
+
+    /// Baby-step giant-step: smallest k with [k]base == target, None if the
+    /// target isn't in the subgroup generated by base. Takes ~2·sqrt(17) additions.
+    #[allow(dead_code)]
+    pub fn discrete_log(&self, base: &Point, target: &Point) -> Option<u64> {
+        if target.is_infinity {
+            return Some(0);
+        }
+        let m = (self.order as f64).sqrt().ceil() as u64;
+
+        // Baby steps: [j]base for j in [0, m)
+        let mut baby_steps = Vec::with_capacity(m as usize);
+        let mut current = Point::infinity();
+        for _ in 0..m {
+            baby_steps.push(current.clone());
+            current = self.add(&current, base);
+        }
+        // current = [m]base, giant steps subtract it
+        let giant_step = Point {
+            x: current.x.clone(),
+            y: current.y.negate(),
+            is_infinity: current.is_infinity,
+        };
+
+        let mut gamma = target.clone();
+        for i in 0..m {
+            if let Some(j) = baby_steps.iter().position(|p| p == &gamma) {
+                return Some((i * m + j as u64) % self.order);
+            }
+            gamma = self.add(&gamma, &giant_step);
+        }
+        None
+    }
+
+    /// Try-and-increment map into the order-17 subgroup: hash the input to an
+    /// x-coordinate, step x until x^3 + ax + b is a square, then clear the
+    /// cofactor. Not constant time, which is fine for deriving public generators.
+    #[allow(dead_code)]
+    pub fn hash_to_curve(&self, input: &[u8]) -> Point {
+        let mut x = hash_to_field(b"hash_to_curve", input);
+        loop {
+            if let Some(y) = self.curve_rhs(&x).sqrt() {
+                let candidate = Point {
+                    x: x.clone(),
+                    y,
+                    is_infinity: false,
+                };
+                let point = self.clear_cofactor(&candidate);
+                if !point.is_infinity {
+                    return point;
+                }
+            }
+            x = x.add(&FieldElement::one());
+        }
+    }
+
+    /// Uniformly random point of the subgroup generated by g1 (infinity
+    /// included): a uniform curve point with the cofactor cleared. [cofactor]
+    /// maps the curve cofactor-to-1 onto the subgroup, so the result is
+    /// uniform as well.
+    #[allow(dead_code)]
+    pub fn random_point<R: Rng>(&self, rng: &mut R) -> Point {
+        self.clear_cofactor(&self.random_curve_point(rng))
+    }
+
+    /// Uniformly random point of the whole curve by rejection sampling. Each
+    /// round draws one of the 2(p + 1) slots (x or infinity, sign bit). A pair
+    /// (x, ±y) owns one slot per sign, while infinity and the points with
+    /// y = 0 only own the slot with sign 0, so every point is hit equally often.
+    fn random_curve_point<R: Rng>(&self, rng: &mut R) -> Point {
+        loop {
+            let candidate = rng.gen_range(0..=FieldElement::MODULUS);
+            let negate = rng.gen::<bool>();
+            if candidate == FieldElement::MODULUS {
+                if !negate {
+                    return Point::infinity();
+                }
+                continue;
+            }
+            let x = FieldElement::new(candidate);
+            let Some(y) = self.curve_rhs(&x).sqrt() else {
+                continue;
+            };
+            if y == FieldElement::zero() && negate {
+                continue;
+            }
+            return Point {
+                x,
+                y: if negate { y.negate() } else { y },
+                is_infinity: false,
+            };
+        }
+    }
+
+    /// x^3 + ax + b
+    fn curve_rhs(&self, x: &FieldElement) -> FieldElement {
+        x.multiply(x)
+            .multiply(x)
+            .add(&self.a.multiply(x))
+            .add(&self.b)
+    }
+
+    /// [cofactor]P lands any point in the subgroup. For y^2 = x^3 + 3 over
+    /// F_101 (p = 2 mod 3, supersingular) there are p + 1 = 102 = 6·17 points.
+    fn clear_cofactor(&self, p: &Point) -> Point {
+        // Not reduced by the subgroup order, unlike scalar_mul_u64
+        p.double_and_add(self, self.cofactor)
+    }
+
+    /// acc += other in place, for accumulation loops: the sum is written into
+    /// acc's coordinates instead of building a new point. Adding infinity
+    /// leaves acc untouched. `scalar_mul` accumulates the same way with
+    /// `JacobianPoint::add_assign`, skipping the inversion paid here.
+    #[allow(dead_code)]
+    pub fn add_assign(&self, acc: &mut Point, other: &Point) {
+        if other.is_infinity {
+            return;
+        }
+        if acc.is_infinity {
+            acc.clone_from(other);
+            return;
+        }
+        let m: FieldElement = if acc.x != other.x {
+            other.y.substract(&acc.y).divide(&other.x.substract(&acc.x))
+        } else if acc.y == other.y && acc.y != FieldElement::zero() {
+            // acc == other, tangent slope as in `double`
+            acc.x
+                .multiply(&acc.x)
+                .multiply(&FieldElement::new(3))
+                .add(&self.a)
+                .divide(&acc.y.multiply(&FieldElement::new(2)))
+        } else {
+            // other == -acc, including doubling a point of order two
+            *acc = Point::infinity();
+            return;
+        };
+        let x3: FieldElement = m.multiply(&m).substract(&acc.x).substract(&other.x);
+        acc.y = m.multiply(&acc.x.substract(&x3)).substract(&acc.y);
+        acc.x = x3;
+    }
+
+    /// [2]P with the tangent slope m = (3x^2 + a) / 2y
+    #[allow(dead_code)]
+    pub fn double(&self, p: &Point) -> Point {
+        if p.is_infinity || p.y == FieldElement::zero() {
+            return Point::infinity(); // vertical tangent
+        }
+        let num: FieldElement =
+            p.x.multiply(&p.x)
+                .multiply(&FieldElement::new(3))
+                .add(&self.a);
+        let den = p.y.multiply(&FieldElement::new(2));
+        let m: FieldElement = num.divide(&den);
+
+        let x3: FieldElement = m.multiply(&m).substract(&p.x).substract(&p.x);
+        let y3: FieldElement = m.multiply(&p.x.substract(&x3)).substract(&p.y);
+        Point {
+            x: x3,
+            y: y3,
+            is_infinity: false,
+        }
+    }
+
     pub fn add_ext(&self, p1: &PointExt, p2: &PointExt) -> PointExt {
         if p1.is_infinity {
             return p2.clone();
@@ -176,18 +744,11 @@ impl EllipticCurve {
                 FieldElement::new(2),
                 FieldElement::zero(),
             ));
-            // Simplified: This needs proper division in F_101^2
-            let m_num = num.a; // Toy approximation
-            let m_den = den.a;
-            let m_field = m_num.divide(&m_den);
-            FieldElementExt::new(m_field, FieldElement::zero())
+            num.divide(&den)
         } else {
             let num = p1.y.substract(&p2.y);
             let den = p1.x.substract(&p2.x);
-            let m_num = num.a;
-            let m_den = den.a;
-            let m_field = m_num.divide(&m_den);
-            FieldElementExt::new(m_field, FieldElement::zero())
+            num.divide(&den)
         };
 
         let x3 = m.multiply(&m).substract(&p1.x).substract(&p2.x);
@@ -246,11 +807,54 @@ mod tests {
     fn test_curve_order() {
         let curve = EllipticCurve::new();
         let g1 = curve.generator_g1();
-        let order = FieldElement::new(curve.order);
-        let result = g1.scalar_mul(&curve, order);
+        // [r - 1]G + G = [r]G = O
+        let order_minus_one = Scalar::new(curve.order - 1);
+        let result = curve.add(&g1.scalar_mul(&curve, order_minus_one), &g1);
         assert_eq!(result, Point::infinity());
     }
 
+    #[test]
+    fn test_scalar_mul_u64_reduces_by_order() {
+        let curve = EllipticCurve::new();
+        let g1 = curve.generator_g1();
+        assert_eq!(g1.scalar_mul_u64(&curve, 17), g1.scalar_mul_u64(&curve, 0));
+        assert_eq!(g1.scalar_mul_u64(&curve, 17), Point::infinity());
+        assert_eq!(g1.scalar_mul_u64(&curve, 18), g1.scalar_mul_u64(&curve, 1));
+        assert_eq!(g1.scalar_mul_u64(&curve, 18), g1);
+    }
+
+    #[test]
+    fn test_generator_table_matches_scalar_mul() {
+        let curve = EllipticCurve::new();
+        let g1 = curve.generator_g1();
+        for window in 1..=3 {
+            let table = curve.precompute_generator_table(window);
+            for k in 0..curve.order {
+                assert_eq!(
+                    table.mul(Scalar::new(k)),
+                    g1.scalar_mul(&curve, Scalar::new(k))
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_curve_order_g2() {
+        let curve = EllipticCurve::new();
+        let g2 = curve.generator_g2();
+        let result = curve.add_ext(&g2.scalar_mul(&curve, Scalar::new(16)), &g2);
+        assert_eq!(result, PointExt::infinity());
+        // [3]H = (10, 16u)
+        assert_eq!(
+            g2.scalar_mul(&curve, Scalar::new(3)),
+            PointExt {
+                x: FieldElementExt::new(FieldElement::new(10), FieldElement::zero()),
+                y: FieldElementExt::new(FieldElement::zero(), FieldElement::new(16)),
+                is_infinity: false,
+            }
+        );
+    }
+
     #[test]
     fn test_double_g1() {
         let curve = EllipticCurve::new();
@@ -265,4 +869,439 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn test_double_matches_add() {
+        let curve = EllipticCurve::new();
+        let g1 = curve.generator_g1();
+        assert_eq!(
+            curve.double(&g1),
+            Point {
+                x: FieldElement::new(68),
+                y: FieldElement::new(74),
+                is_infinity: false,
+            }
+        );
+        let mut p = g1.clone();
+        for _ in 0..curve.order() {
+            assert_eq!(curve.double(&p), curve.add(&p, &p));
+            p = curve.add(&p, &g1);
+        }
+        assert!(curve.double(&curve.infinity()).is_infinity);
+    }
+
+    #[test]
+    fn test_jacobian_scalar_mul_matches_affine() {
+        let curve = EllipticCurve::new();
+        let g1 = curve.generator_g1();
+        // [s]G by repeated affine additions
+        let mut expected = curve.infinity();
+        for s in 0..=curve.order() {
+            assert_eq!(g1.scalar_mul_u64(&curve, s), expected);
+            assert_eq!(
+                JacobianPoint::from_affine(&expected)
+                    .double(&curve)
+                    .to_affine(),
+                curve.double(&expected)
+            );
+            expected = curve.add(&expected, &g1);
+        }
+    }
+
+    #[test]
+    fn test_add_assign_matches_add() {
+        let curve = EllipticCurve::new();
+        let g1 = curve.generator_g1();
+        for a in 0..curve.order() {
+            for b in 0..curve.order() {
+                let p = g1.scalar_mul_u64(&curve, a);
+                let q = g1.scalar_mul_u64(&curve, b);
+                let mut acc = p.clone();
+                curve.add_assign(&mut acc, &q);
+                assert_eq!(acc, curve.add(&p, &q));
+            }
+        }
+
+        // Outside the subgroup: (x, 0) with x^3 + 3 = 0 has order two
+        let two_torsion = (0..101)
+            .map(|x| Point {
+                x: FieldElement::new(x),
+                y: FieldElement::zero(),
+                is_infinity: false,
+            })
+            .find(|p| curve.is_on_curve(p))
+            .unwrap();
+        let mut acc = two_torsion.clone();
+        curve.add_assign(&mut acc, &two_torsion);
+        assert_eq!(acc, Point::infinity());
+    }
+
+    #[test]
+    fn test_jacobian_add_assign_matches_add() {
+        let curve = EllipticCurve::new();
+        let g1 = curve.generator_g1();
+        for a in 0..curve.order() {
+            for b in 0..curve.order() {
+                // Doubled, so Z isn't 1 and the formulas see real Jacobian inputs
+                let p = JacobianPoint::from_affine(&g1.scalar_mul_u64(&curve, a)).double(&curve);
+                let q = JacobianPoint::from_affine(&g1.scalar_mul_u64(&curve, b)).double(&curve);
+                let mut acc = p.clone();
+                acc.add_assign(&q, &curve);
+                let expected = curve.add(&p.to_affine(), &q.to_affine());
+                assert_eq!(acc.to_affine(), expected);
+                assert_eq!(p.add(&q, &curve).to_affine(), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_scalar_mul_powers() {
+        let curve = EllipticCurve::new();
+        let g = curve.generator_g1();
+        let tau = Scalar::new(5);
+        let powers = curve.scalar_mul_powers(&g, FieldElement::new(5), 20);
+        assert_eq!(powers.len(), 21);
+        for (n, point) in powers.iter().enumerate() {
+            assert_eq!(point, &g.scalar_mul(&curve, tau.pow(n as u64)));
+        }
+    }
+
+    #[test]
+    fn test_discrete_log() {
+        let curve = EllipticCurve::new();
+        let g = curve.generator_g1();
+        assert_eq!(
+            curve.discrete_log(&g, &g.scalar_mul(&curve, Scalar::new(5))),
+            Some(5)
+        );
+        for k in 0..curve.order() {
+            let target = g.scalar_mul_u64(&curve, k);
+            assert_eq!(curve.discrete_log(&g, &target), Some(k));
+        }
+
+        // (48, 0) has order 2, outside the order-17 subgroup
+        let two_torsion = Point {
+            x: FieldElement::new(48),
+            y: FieldElement::zero(),
+            is_infinity: false,
+        };
+        assert!(curve.is_on_curve(&two_torsion));
+        assert_eq!(curve.discrete_log(&g, &two_torsion), None);
+    }
+
+    #[test]
+    fn test_hash_to_curve() {
+        let curve = EllipticCurve::new();
+        let p = curve.hash_to_curve(b"generator 0");
+        assert_eq!(p, curve.hash_to_curve(b"generator 0"));
+        assert!(!p.is_infinity);
+        assert!(curve.is_on_curve(&p));
+        // In the subgroup: [17]P = O
+        assert!(p.double_and_add(&curve, curve.order()).is_infinity);
+
+        let q = curve.hash_to_curve(b"generator 1");
+        assert!(curve.is_on_curve(&q));
+        assert!(q.double_and_add(&curve, curve.order()).is_infinity);
+    }
+
+    /// y^2 = x^3 + x + 9 has 119 = 7·17 points, (73, 59) has order 17.
+    /// Over F_101^2, (9, 9u) is another point of order 17.
+    fn custom_curve() -> EllipticCurve {
+        let g1 = Point {
+            x: FieldElement::new(73),
+            y: FieldElement::new(59),
+            is_infinity: false,
+        };
+        let g2 = PointExt {
+            x: FieldElementExt::new(FieldElement::new(9), FieldElement::zero()),
+            y: FieldElementExt::new(FieldElement::zero(), FieldElement::new(9)),
+            is_infinity: false,
+        };
+        EllipticCurve::with_params(FieldElement::new(1), FieldElement::new(9), g1, g2, 17).unwrap()
+    }
+
+    #[test]
+    fn test_with_params() {
+        use ark_std::rand::{rngs::StdRng, SeedableRng};
+
+        let curve = custom_curve();
+        assert_eq!(curve.point_count(), 119);
+        assert_eq!(curve.cofactor(), 7);
+        assert_eq!(EllipticCurve::new().point_count(), 102);
+        assert_eq!(EllipticCurve::new().cofactor(), 6);
+
+        let g1 = curve.generator_g1();
+        assert_eq!(curve.point_order(&g1), 17);
+        let five_g = g1.scalar_mul(&curve, Scalar::new(5));
+        assert_eq!(g1.scalar_mul_u64(&curve, 22), five_g);
+        assert_eq!(curve.discrete_log(&g1, &five_g), Some(5));
+        assert!(
+            curve
+                .add(&g1.scalar_mul(&curve, Scalar::new(16)), &g1)
+                .is_infinity
+        );
+
+        // Cofactor 7 is cleared, both maps land in the order-17 subgroup
+        let hashed = curve.hash_to_curve(b"custom");
+        assert!(curve.is_on_curve(&hashed));
+        assert_eq!(curve.point_order(&hashed), 17);
+        let mut rng = StdRng::seed_from_u64(1862);
+        let mut seen = Vec::new();
+        for _ in 0..1000 {
+            let p = curve.random_point(&mut rng);
+            assert!(curve.discrete_log(&g1, &p).is_some());
+            if !seen.contains(&p) {
+                seen.push(p);
+            }
+        }
+        assert_eq!(seen.len(), 17);
+    }
+
+    #[test]
+    fn test_with_params_rejects_invalid() {
+        // y^2 = x^3 is singular (cusp at the origin)
+        let on_cusp = Point {
+            x: FieldElement::new(1),
+            y: FieldElement::new(1),
+            is_infinity: false,
+        };
+        assert_eq!(
+            EllipticCurve::with_params(
+                FieldElement::zero(),
+                FieldElement::zero(),
+                on_cusp,
+                PointExt::infinity(),
+                17,
+            )
+            .unwrap_err(),
+            CurveError::SingularCurve
+        );
+
+        let off_curve = Point {
+            x: FieldElement::new(1),
+            y: FieldElement::new(3),
+            is_infinity: false,
+        };
+        let preset = EllipticCurve::new();
+        assert_eq!(
+            EllipticCurve::with_params(
+                FieldElement::zero(),
+                FieldElement::new(3),
+                off_curve,
+                preset.generator_g2(),
+                17,
+            )
+            .unwrap_err(),
+            CurveError::GeneratorNotOnCurve
+        );
+
+        // y^2 = x^3 + 2x + 3 has 96 points and (1, 39) generates all of them
+        let g96 = Point {
+            x: FieldElement::new(1),
+            y: FieldElement::new(39),
+            is_infinity: false,
+        };
+        let (a, b) = (FieldElement::new(2), FieldElement::new(3));
+        let curve_96 = |order| {
+            EllipticCurve::with_params(
+                a.clone(),
+                b.clone(),
+                g96.clone(),
+                PointExt::from_base(&g96),
+                order,
+            )
+        };
+        assert_eq!(curve_96(17).unwrap_err(), CurveError::GeneratorWrongOrder);
+        assert_eq!(curve_96(48).unwrap_err(), CurveError::GeneratorWrongOrder);
+        let curve = curve_96(96).unwrap();
+        assert_eq!(curve.order(), 96);
+        assert_eq!(curve.cofactor(), 1);
+
+        // (48, 0) has order 2
+        let two_torsion = Point {
+            x: FieldElement::new(48),
+            y: FieldElement::zero(),
+            is_infinity: false,
+        };
+        assert_eq!(
+            EllipticCurve::with_params(
+                FieldElement::zero(),
+                FieldElement::new(3),
+                two_torsion,
+                preset.generator_g2(),
+                17,
+            )
+            .unwrap_err(),
+            CurveError::GeneratorWrongOrder
+        );
+
+        let with_g2 = |g2| {
+            EllipticCurve::with_params(
+                FieldElement::zero(),
+                FieldElement::new(3),
+                preset.generator_g1(),
+                g2,
+                17,
+            )
+        };
+        assert!(with_g2(preset.generator_g2()).is_ok());
+        assert_eq!(
+            with_g2(PointExt::infinity()).unwrap_err(),
+            CurveError::G2NotOnCurve
+        );
+        // (36, 30u) instead of (36, 31u)
+        let off_curve_g2 = PointExt {
+            x: FieldElementExt::new(FieldElement::new(36), FieldElement::zero()),
+            y: FieldElementExt::new(FieldElement::zero(), FieldElement::new(30)),
+            is_infinity: false,
+        };
+        assert_eq!(with_g2(off_curve_g2).unwrap_err(), CurveError::G2NotOnCurve);
+        let two_torsion_g2 = PointExt::from_base(&Point {
+            x: FieldElement::new(48),
+            y: FieldElement::zero(),
+            is_infinity: false,
+        });
+        assert_eq!(
+            with_g2(two_torsion_g2).unwrap_err(),
+            CurveError::G2WrongOrder
+        );
+    }
+
+    #[test]
+    fn test_random_point() {
+        use ark_std::rand::{rngs::StdRng, SeedableRng};
+
+        let curve = EllipticCurve::new();
+        let mut rng = StdRng::seed_from_u64(1);
+        let mut seen = Vec::new();
+        for _ in 0..1000 {
+            let p = curve.random_point(&mut rng);
+            assert!(curve.is_on_curve(&p));
+            assert!(p.double_and_add(&curve, curve.order()).is_infinity);
+            if !seen.contains(&p) {
+                seen.push(p);
+            }
+        }
+        // Every subgroup element shows up
+        assert_eq!(seen.len() as u64, curve.order());
+    }
+
+    #[test]
+    fn test_random_curve_point_is_uniform() {
+        use ark_std::rand::{rngs::StdRng, SeedableRng};
+
+        // 102 points, ~1000 draws each. (48, 0) is the only one with y = 0,
+        // picking a sign for it too would draw it twice as often.
+        let curve = EllipticCurve::new();
+        let mut rng = StdRng::seed_from_u64(1869);
+        let two_torsion = Point {
+            x: FieldElement::new(48),
+            y: FieldElement::zero(),
+            is_infinity: false,
+        };
+        let g1 = curve.generator_g1();
+        let (mut infinity, mut y_zero, mut generator) = (0, 0, 0);
+        for _ in 0..102_000 {
+            let p = curve.random_curve_point(&mut rng);
+            assert!(curve.is_on_curve(&p));
+            if p.is_infinity {
+                infinity += 1;
+            } else if p == two_torsion {
+                y_zero += 1;
+            } else if p == g1 {
+                generator += 1;
+            }
+        }
+        for count in [infinity, y_zero, generator] {
+            assert!((800..1200).contains(&count), "drawn {count} times");
+        }
+    }
+
+    #[test]
+    fn test_is_on_curve() {
+        let curve = EllipticCurve::new();
+        let g1 = curve.generator_g1();
+        assert!(curve.is_on_curve(&g1));
+        assert!(curve.is_on_curve(&curve.add(&g1, &g1)));
+        assert!(curve.is_on_curve(&curve.infinity()));
+        let off_curve = Point {
+            x: FieldElement::new(1),
+            y: FieldElement::new(3),
+            is_infinity: false,
+        };
+        assert!(!curve.is_on_curve(&off_curve));
+    }
+
+    #[test]
+    fn test_point_xy() {
+        assert_eq!(Point::infinity().xy(), None);
+        let curve = EllipticCurve::new();
+        assert_eq!(
+            curve.generator_g1().xy(),
+            Some((FieldElement::new(1), FieldElement::new(2)))
+        );
+    }
+
+    #[test]
+    fn test_scalar_mul_signed() {
+        let curve = EllipticCurve::new();
+        let g = curve.generator_g1();
+        let three_g = g.scalar_mul(&curve, Scalar::new(3));
+        assert_eq!(g.scalar_mul_signed(&curve, -3), three_g.negate());
+        assert_eq!(g.scalar_mul_signed(&curve, 3), three_g);
+        // [-3]G = [14]G in a subgroup of order 17
+        assert_eq!(
+            g.scalar_mul_signed(&curve, -3),
+            g.scalar_mul(&curve, Scalar::new(14))
+        );
+        assert!(curve.add(&three_g, &three_g.negate()).is_infinity);
+        assert!(g.scalar_mul_signed(&curve, 0).is_infinity);
+        assert_eq!(curve.infinity().negate(), curve.infinity());
+    }
+
+    #[test]
+    fn test_scalar_mul_bytes() {
+        let curve = EllipticCurve::new();
+        let g = curve.generator_g1();
+        assert_eq!(
+            g.scalar_mul_bytes(&curve, &[5]),
+            g.scalar_mul(&curve, Scalar::new(5))
+        );
+        // 0x0100 = 256 = 15·17 + 1
+        assert_eq!(g.scalar_mul_bytes(&curve, &[0x00, 0x01]), g);
+        // 22 = 17 + 5
+        assert_eq!(
+            g.scalar_mul_bytes(&curve, &[22, 0, 0, 0, 0, 0, 0, 0, 0]),
+            g.scalar_mul(&curve, Scalar::new(5))
+        );
+        assert!(g.scalar_mul_bytes(&curve, &[]).is_infinity);
+        assert!(g.scalar_mul_bytes(&curve, &[17]).is_infinity);
+    }
+
+    #[test]
+    fn test_point_order() {
+        let curve = EllipticCurve::new();
+        assert_eq!(curve.point_order(&curve.generator_g1()), 17);
+        assert_eq!(curve.point_order(&curve.infinity()), 1);
+        let two_torsion = Point {
+            x: FieldElement::new(48),
+            y: FieldElement::zero(),
+            is_infinity: false,
+        };
+        assert_eq!(curve.point_order(&two_torsion), 2);
+    }
+
+    #[test]
+    fn test_point_ext_from_base() {
+        let curve = EllipticCurve::new();
+        let p = curve.generator_g1();
+        let q = p.scalar_mul(&curve, Scalar::new(5));
+        for (a, b) in [(&p, &q), (&p, &p), (&q, &q.negate())] {
+            let sum = curve.add_ext(&PointExt::from_base(a), &PointExt::from_base(b));
+            assert_eq!(sum.x.b, FieldElement::zero());
+            assert_eq!(sum.y.b, FieldElement::zero());
+            assert_eq!(sum, PointExt::from_base(&curve.add(a, b)));
+        }
+        assert!(PointExt::from_base(&curve.infinity()).is_infinity);
+    }
 }
@@ -1,6 +1,9 @@
 use super::ext_euclidean_algo as gcd;
+use crate::field_trait::Field;
+use ark_std::rand::Rng;
 
-use std::ops::{AddAssign, Div, Mul};
+use std::iter::{Product, Sum};
+use std::ops::{Add, AddAssign, Div, Mul, Neg};
 
 #[derive(Clone, Debug)]
 pub struct FieldElement {
@@ -9,6 +12,8 @@ pub struct FieldElement {
 
 impl FieldElement {
     pub const MODULUS: u64 = 101;
+    /// 2 is a primitive root mod 101, it generates the whole F_101^*
+    pub const MULTIPLICATIVE_GENERATOR: u64 = 2;
 
     /// Constructor
     pub fn new(value: u64) -> Self {
@@ -53,6 +58,13 @@ impl FieldElement {
         }
     }
 
+    /// Inverse by Fermat's little theorem: a^(p-2) = a⁻¹ for a prime p.
+    /// No gcd branching, and zero maps to zero like `inverse`.
+    #[allow(dead_code)]
+    pub fn inverse_fermat(&self) -> Self {
+        self.pow(Self::MODULUS - 2)
+    }
+
     /// Division (a / b = a * b⁻¹ mod p)
     #[allow(dead_code)]
     pub fn divide(&self, other: &Self) -> Self {
@@ -76,7 +88,7 @@ impl FieldElement {
                 let bin: u64 = (exp >> n) & 1;
                 if fsb_found {
                     if bin == 1 {
-                        result = result.multiply(&result).multiply(&self);
+                        result = result.multiply(&result).multiply(self);
                     } else {
                         result = result.multiply(&result);
                     }
@@ -90,10 +102,126 @@ impl FieldElement {
         }
     }
 
-    /// Negation in the FieldElement
+    /// Square-and-multiply over an exponent of any width, given as
+    /// little-endian bits (bits[0] is the least significant one)
+    #[allow(dead_code)]
+    pub fn pow_bits(&self, bits: &[bool]) -> Self {
+        let mut result = Self::one();
+        for bit in bits.iter().rev() {
+            result = result.multiply(&result);
+            if *bit {
+                result = result.multiply(self);
+            }
+        }
+        result
+    }
+
+    /// Exponent given as little-endian u64 limbs (limbs[0] is the least significant one)
+    #[allow(dead_code)]
+    pub fn pow_limbs(&self, limbs: &[u64]) -> Self {
+        let bits: Vec<bool> = limbs
+            .iter()
+            .flat_map(|limb| (0..64).map(move |n| (limb >> n) & 1 == 1))
+            .collect();
+        self.pow_bits(&bits)
+    }
+
+    /// Smallest k > 0 with self^k == 1, None for zero. By Lagrange the order
+    /// divides MODULUS - 1, so only its divisors are tried.
+    #[allow(dead_code)]
+    pub fn multiplicative_order(&self) -> Option<u64> {
+        if *self == Self::zero() {
+            return None;
+        }
+        let group_order = Self::MODULUS - 1;
+        (1..=group_order)
+            .filter(|k| group_order.is_multiple_of(*k))
+            .find(|k| self.pow(*k) == Self::one())
+    }
+
+    /// [ω^0, ω^1, ..., ω^(n-1)] for ω = g^((MODULUS - 1) / n), a primitive n-th
+    /// root of unity since g generates F_101^*. None unless n divides MODULUS - 1.
+    #[allow(dead_code)]
+    pub fn roots_of_unity(n: u64) -> Option<Vec<Self>> {
+        let group_order = Self::MODULUS - 1;
+        if n == 0 || !group_order.is_multiple_of(n) {
+            return None;
+        }
+        let omega = Self::new(Self::MULTIPLICATIVE_GENERATOR).pow(group_order / n);
+        let mut roots = Vec::with_capacity(n as usize);
+        let mut current = Self::one();
+        for _ in 0..n {
+            roots.push(current.clone());
+            current = current.multiply(&omega);
+        }
+        Some(roots)
+    }
+
+    /// Legendre symbol (self / p) by Euler's criterion: 1 for a nonzero
+    /// square, -1 for a non-square and 0 for zero
+    #[allow(dead_code)]
+    pub fn legendre(&self) -> i8 {
+        let symbol = self.pow((Self::MODULUS - 1) / 2);
+        if symbol == Self::zero() {
+            0
+        } else if symbol == Self::one() {
+            1
+        } else {
+            -1
+        }
+    }
+
+    /// Square root by Tonelli-Shanks, None for non-squares. Of the two roots
+    /// the one with the smaller value is returned.
+    #[allow(dead_code)]
+    pub fn sqrt(&self) -> Option<Self> {
+        match self.legendre() {
+            0 => return Some(Self::zero()),
+            -1 => return None,
+            _ => {}
+        }
+        // p - 1 = q·2^s with q odd
+        let mut q = Self::MODULUS - 1;
+        let mut s = 0;
+        while q.is_multiple_of(2) {
+            q /= 2;
+            s += 1;
+        }
+        let non_residue = (2..Self::MODULUS)
+            .map(Self::new)
+            .find(|z| z.legendre() == -1)
+            .unwrap();
+
+        let mut m = s;
+        let mut c = non_residue.pow(q);
+        let mut t = self.pow(q);
+        let mut r = self.pow(q.div_ceil(2));
+        while t != Self::one() {
+            // least i with t^(2^i) == 1
+            let mut i = 0;
+            let mut t_pow = t.clone();
+            while t_pow != Self::one() {
+                t_pow = t_pow.multiply(&t_pow);
+                i += 1;
+            }
+            let b = c.pow(1 << (m - i - 1));
+            m = i;
+            c = b.multiply(&b);
+            t = t.multiply(&c);
+            r = r.multiply(&b);
+        }
+
+        let other = r.negate();
+        Some(if other.value < r.value { other } else { r })
+    }
+
+    /// Negation in the FieldElement: p - v, and zero stays zero
     #[allow(dead_code)]
     pub fn negate(&self) -> Self {
-        Self::new(Self::MODULUS).substract(&self)
+        if self.value == 0 {
+            return Self::zero();
+        }
+        Self::new(Self::MODULUS - self.value)
     }
 
     /// Zero element
@@ -105,9 +233,116 @@ impl FieldElement {
     pub fn one() -> Self {
         Self::new(1)
     }
+
+    /// Lowercase hex with a 0x prefix, e.g. 100 -> "0x64"
+    #[allow(dead_code)]
+    pub fn to_hex(&self) -> String {
+        format!("{:#x}", self.value)
+    }
+
+    /// Parses hex with an optional 0x prefix, rejecting values >= MODULUS
+    #[allow(dead_code)]
+    pub fn from_hex(s: &str) -> Result<Self, ParseFieldElementError> {
+        let digits = s
+            .strip_prefix("0x")
+            .or_else(|| s.strip_prefix("0X"))
+            .unwrap_or(s);
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(ParseFieldElementError::InvalidDigit);
+        }
+        // Anything wider than a u64 is out of range anyway
+        let value =
+            u64::from_str_radix(digits, 16).map_err(|_| ParseFieldElementError::OutOfRange)?;
+        Self::from_u64_checked(value)
+    }
+
+    /// Big-endian encoding of the canonical value
+    #[allow(dead_code)]
+    pub fn to_be_bytes(&self) -> [u8; 8] {
+        self.value.to_be_bytes()
+    }
+
+    /// Inverse of `to_be_bytes`, rejecting values >= MODULUS
+    #[allow(dead_code)]
+    pub fn from_be_bytes(bytes: [u8; 8]) -> Result<Self, ParseFieldElementError> {
+        Self::from_u64_checked(u64::from_be_bytes(bytes))
+    }
+
+    fn from_u64_checked(value: u64) -> Result<Self, ParseFieldElementError> {
+        if value >= Self::MODULUS {
+            return Err(ParseFieldElementError::OutOfRange);
+        }
+        Ok(Self { value })
+    }
+
+    /// Uniformly random element in [0, MODULUS). Draws above the largest
+    /// multiple of MODULUS that fits in a u64 are rejected to avoid modulo bias.
+    #[allow(dead_code)]
+    pub fn rand<R: Rng>(rng: &mut R) -> Self {
+        let limit = u64::MAX - u64::MAX % Self::MODULUS;
+        loop {
+            let value = rng.next_u64();
+            if value < limit {
+                return Self::new(value);
+            }
+        }
+    }
+
+    /// Uniformly random element in [1, MODULUS)
+    #[allow(dead_code)]
+    pub fn rand_nonzero<R: Rng>(rng: &mut R) -> Self {
+        loop {
+            let element = Self::rand(rng);
+            if element != Self::zero() {
+                return element;
+            }
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ParseFieldElementError {
+    InvalidDigit,
+    OutOfRange, // value >= FieldElement::MODULUS
+}
+
+/// Largest s with 2^s dividing modulus - 1: the biggest power-of-two
+/// subgroup of F_modulus^*, so the largest radix-2 FFT it supports.
+/// F_101 only gets 2 (100 = 2^2·25).
+#[allow(dead_code)]
+pub const fn two_adicity(modulus: u64) -> u32 {
+    (modulus - 1).trailing_zeros()
 }
 
-/// Implementations to facilitate writing the code in polynomials
+/// Smallest prime p with two_adicity(p) >= min_two_adicity, i.e. the first
+/// prime of the form k·2^s + 1. E.g. 97 for 5, 257 for 8, 7681 for 9.
+/// None when the candidates overflow a u64 before a prime is found.
+#[allow(dead_code)]
+pub fn recommended_fft_prime(min_two_adicity: u32) -> Option<u64> {
+    let step = 1u64.checked_shl(min_two_adicity)?;
+    let mut candidate = step.checked_add(1)?;
+    while !is_prime(candidate) {
+        candidate = candidate.checked_add(step)?;
+    }
+    Some(candidate)
+}
+
+/// Trial division, fine for the small moduli of this toy field
+fn is_prime(n: u64) -> bool {
+    if n < 2 {
+        return false;
+    }
+    let mut d = 2;
+    while d <= n / d {
+        if n.is_multiple_of(d) {
+            return false;
+        }
+        d += 1;
+    }
+    true
+}
+
+// Implementations to facilitate writing the code in polynomials
 
 impl Mul for FieldElement {
     type Output = Self;
@@ -116,6 +351,52 @@ impl Mul for FieldElement {
     }
 }
 
+impl Add for FieldElement {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        FieldElement::add(&self, &other)
+    }
+}
+
+// Keeps `a.add(&b)` compiling on owned values, which would otherwise
+// resolve to the by-value `Add::add` above
+impl Add<&FieldElement> for FieldElement {
+    type Output = Self;
+    fn add(self, other: &Self) -> Self {
+        FieldElement::add(&self, other)
+    }
+}
+
+// num-traits (re-exported by ark_std), so generic code bounded on Zero/One
+// works with this backend too
+impl ark_std::Zero for FieldElement {
+    fn zero() -> Self {
+        FieldElement::zero()
+    }
+
+    fn is_zero(&self) -> bool {
+        self.value == 0
+    }
+}
+
+impl ark_std::One for FieldElement {
+    fn one() -> Self {
+        FieldElement::one()
+    }
+}
+
+impl Sum for FieldElement {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(<Self as ark_std::Zero>::zero(), |acc, x| acc + x)
+    }
+}
+
+impl Product for FieldElement {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(<Self as ark_std::One>::one(), |acc, x| acc * x)
+    }
+}
+
 impl Default for FieldElement {
     fn default() -> Self {
         Self::zero()
@@ -124,7 +405,7 @@ impl Default for FieldElement {
 
 impl AddAssign for FieldElement {
     fn add_assign(&mut self, other: Self) {
-        *self = Self::add(&self, &other);
+        *self = Self::add(self, &other);
     }
 }
 
@@ -142,61 +423,389 @@ impl Div for FieldElement {
     }
 }
 
+impl From<u32> for FieldElement {
+    fn from(value: u32) -> Self {
+        Self::new(value as u64)
+    }
+}
+
+impl From<u64> for FieldElement {
+    fn from(value: u64) -> Self {
+        Self::new(value)
+    }
+}
+
+// Negative values wrap around: -1 is MODULUS - 1
+impl From<i64> for FieldElement {
+    fn from(value: i64) -> Self {
+        Self::new(value.rem_euclid(Self::MODULUS as i64) as u64)
+    }
+}
+
+// Decimal digits only, rejecting values >= MODULUS like `from_hex`
+impl TryFrom<&str> for FieldElement {
+    type Error = ParseFieldElementError;
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(ParseFieldElementError::InvalidDigit);
+        }
+        let value: u64 = s.parse().map_err(|_| ParseFieldElementError::OutOfRange)?;
+        Self::from_u64_checked(value)
+    }
+}
+
+impl Field for FieldElement {
+    fn zero() -> Self {
+        FieldElement::zero()
+    }
+
+    fn one() -> Self {
+        FieldElement::one()
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        FieldElement::add(self, other)
+    }
+
+    fn sub(&self, other: &Self) -> Self {
+        self.substract(other)
+    }
+
+    fn mul(&self, other: &Self) -> Self {
+        self.multiply(other)
+    }
+
+    fn inverse(&self) -> Self {
+        FieldElement::inverse(self)
+    }
+}
+
+/*
+    Scalar field F_r, with r = 17 the order of the EC subgroup generated by G1.
+    Coordinates live in F_101 (FieldElement) but scalars multiplying points
+    must be reduced mod 17, otherwise [a]P and [a mod 101]P disagree.
+*/
+#[derive(Clone, Debug, PartialEq)]
+pub struct Scalar {
+    pub value: u64,
+}
+
+impl Scalar {
+    pub const MODULUS: u64 = 17;
+
+    /// Constructor
+    pub fn new(value: u64) -> Self {
+        Self {
+            value: value % Self::MODULUS,
+        }
+    }
+
+    /// Reinterprets a base field element as a scalar (value reduced mod 17)
+    #[allow(dead_code)]
+    pub fn from_field_element(element: &FieldElement) -> Self {
+        Self::new(element.value)
+    }
+
+    /// Addition in the scalar field
+    #[allow(dead_code)]
+    pub fn add(&self, other: &Self) -> Self {
+        Self::new(self.value + other.value)
+    }
+
+    /// Subtraction in the scalar field
+    #[allow(dead_code)]
+    pub fn substract(&self, other: &Self) -> Self {
+        Self::new(self.value + Self::MODULUS - other.value)
+    }
+
+    /// Multiplication in the scalar field
+    #[allow(dead_code)]
+    pub fn multiply(&self, other: &Self) -> Self {
+        Self::new(self.value * other.value)
+    }
+
+    /// Modular inverse, zero when no inverse exists
+    #[allow(dead_code)]
+    pub fn inverse(&self) -> Self {
+        let (s, _, gcd) = gcd::ext_gcd(self.value as i64, Self::MODULUS as i64);
+        if gcd != 1 {
+            return Self::zero();
+        }
+        let modulus = Self::MODULUS as i64;
+        Self::new(((s % modulus + modulus) % modulus) as u64)
+    }
+
+    /// Division (a / b = a * b⁻¹ mod r)
+    #[allow(dead_code)]
+    pub fn divide(&self, other: &Self) -> Self {
+        self.multiply(&other.inverse())
+    }
+
+    /// Modular exponentiation (a^exp mod r) using square and multiply
+    #[allow(dead_code)]
+    pub fn pow(&self, exp: u64) -> Self {
+        let mut result = Self::one();
+        let mut base = self.clone();
+        let mut e = exp;
+        while e > 0 {
+            if e & 1 == 1 {
+                result = result.multiply(&base);
+            }
+            base = base.multiply(&base);
+            e >>= 1;
+        }
+        result
+    }
+
+    /// Negation in the scalar field
+    #[allow(dead_code)]
+    pub fn negate(&self) -> Self {
+        Self::zero().substract(self)
+    }
+
+    /// Zero element
+    pub fn zero() -> Self {
+        Self::new(0)
+    }
+
+    /// One element
+    pub fn one() -> Self {
+        Self::new(1)
+    }
+}
+
+impl Field for Scalar {
+    fn zero() -> Self {
+        Scalar::zero()
+    }
+
+    fn one() -> Self {
+        Scalar::one()
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        Scalar::add(self, other)
+    }
+
+    fn sub(&self, other: &Self) -> Self {
+        self.substract(other)
+    }
+
+    fn mul(&self, other: &Self) -> Self {
+        self.multiply(other)
+    }
+
+    fn inverse(&self) -> Self {
+        Scalar::inverse(self)
+    }
+}
+
 /*
     Field element extension F_(p^k)
+
+    F_101^2 = F_101[u] / (u^2 - β) for a quadratic non-residue β, so that
+    u^2 - β has no root in F_101. The curve code uses β = -2; other
+    non-residues give isomorphic fields with different coordinates.
 */
 #[derive(Clone, Debug, PartialEq)]
 pub struct FieldElementExt {
-    pub a: FieldElement, // Real part
-    pub b: FieldElement, // Imaginary part (coefficient of u)
+    pub a: FieldElement,          // Real part
+    pub b: FieldElement,          // Imaginary part (coefficient of u)
+    pub nonresidue: FieldElement, // β = u^2
 }
 
 impl FieldElementExt {
+    /// u^2 = -2 (mod 101)
+    pub const DEFAULT_NONRESIDUE: u64 = FieldElement::MODULUS - 2;
+
     #[allow(dead_code)]
     pub fn new(a: FieldElement, b: FieldElement) -> Self {
-        FieldElementExt { a, b }
+        FieldElementExt {
+            a,
+            b,
+            nonresidue: FieldElement::new(Self::DEFAULT_NONRESIDUE),
+        }
     }
 
+    /// Element a + bu of F_101[u] / (u^2 - nr).
+    /// Panics if nr is a square, the quotient wouldn't be a field then.
     #[allow(dead_code)]
-    pub fn add(&self, other: &Self) -> Self {
+    pub fn with_nonresidue(a: FieldElement, b: FieldElement, nr: FieldElement) -> Self {
+        assert!(nr.legendre() == -1, "u^2 must be a quadratic non-residue");
         FieldElementExt {
-            a: self.a.add(&other.a),
-            b: self.b.add(&other.b),
+            a,
+            b,
+            nonresidue: nr,
         }
     }
 
-    #[allow(dead_code)]
-    pub fn substract(&self, other: &Self) -> Self {
+    /// Builds an element living in the same extension as self. Constants that
+    /// meet a custom non-residue element have to be built this way, `zero()`,
+    /// `one()` and `from_base()` use the default non-residue.
+    pub(crate) fn with_parts(&self, a: FieldElement, b: FieldElement) -> Self {
         FieldElementExt {
-            a: self.a.substract(&other.a),
-            b: self.b.substract(&other.b),
+            a,
+            b,
+            nonresidue: self.nonresidue.clone(),
         }
     }
 
+    #[allow(dead_code)]
+    pub fn add(&self, other: &Self) -> Self {
+        debug_assert_eq!(self.nonresidue, other.nonresidue);
+        self.with_parts(
+            FieldElement::add(&self.a, &other.a),
+            FieldElement::add(&self.b, &other.b),
+        )
+    }
+
+    #[allow(dead_code)]
+    pub fn substract(&self, other: &Self) -> Self {
+        debug_assert_eq!(self.nonresidue, other.nonresidue);
+        self.with_parts(self.a.substract(&other.a), self.b.substract(&other.b))
+    }
+
+    /// -(a + bu) = -a - bu
+    #[allow(dead_code)]
+    pub fn negate(&self) -> Self {
+        self.with_parts(self.a.negate(), self.b.negate())
+    }
+
     #[allow(dead_code)]
     pub fn multiply(&self, other: &Self) -> Self {
+        debug_assert_eq!(self.nonresidue, other.nonresidue);
         // (a + bu)(c + du) = (ac + bd u^2) + (ad + bc)u
-        // u^2 = -2, so bd u^2 = bd(-2)
+        // u^2 = β, so bd u^2 = bdβ
         let ac = self.a.multiply(&other.a);
         let ad_u = self.a.multiply(&other.b);
         let bc_u = self.b.multiply(&other.a);
-        let bd_u2 = self
-            .b
-            .multiply(&other.b)
-            .multiply(&FieldElement::new(101 - 2)); // u^2 = -2
+        let bd_u2 = self.b.multiply(&other.b).multiply(&self.nonresidue);
 
         let real = ac.add(&bd_u2);
         let imag = ad_u.add(&bc_u);
 
-        FieldElementExt { a: real, b: imag }
+        self.with_parts(real, imag)
     }
 
     #[allow(dead_code)]
     pub fn scalar_mul(&self, scalar: FieldElement) -> Self {
-        FieldElementExt {
-            a: self.a.multiply(&scalar),
-            b: self.b.multiply(&scalar),
+        self.with_parts(self.a.multiply(&scalar), self.b.multiply(&scalar))
+    }
+
+    /// a - bu, the image of a + bu under the Frobenius map x -> x^101
+    #[allow(dead_code)]
+    pub fn conjugate(&self) -> Self {
+        self.with_parts(self.a.clone(), self.b.negate())
+    }
+
+    /// (a + bu)(a - bu) = a^2 - βb^2, always in the base field
+    #[allow(dead_code)]
+    pub fn norm(&self) -> FieldElement {
+        self.a
+            .multiply(&self.a)
+            .substract(&self.b.multiply(&self.b).multiply(&self.nonresidue))
+    }
+
+    /// Inverse in F_101^2: (a + bu)⁻¹ = (a - bu) / (a^2 - βb^2), since u^2 = β.
+    /// Returns zero for the zero element.
+    #[allow(dead_code)]
+    pub fn inverse(&self) -> Self {
+        self.conjugate().scalar_mul(self.norm().inverse())
+    }
+
+    #[allow(dead_code)]
+    pub fn divide(&self, other: &Self) -> Self {
+        self.multiply(&other.inverse())
+    }
+
+    /// Exponentiation in F_101^2 using square and multiply
+    #[allow(dead_code)]
+    pub fn pow(&self, exp: u64) -> Self {
+        let mut result = self.with_parts(FieldElement::one(), FieldElement::zero());
+        let mut base = self.clone();
+        let mut e = exp;
+        while e > 0 {
+            if e & 1 == 1 {
+                result = result.multiply(&base);
+            }
+            base = base.multiply(&base);
+            e >>= 1;
+        }
+        result
+    }
+
+    /// All powers self^0, self^1, ..., self^(2^window - 1), the table
+    /// `pow_fixed` looks windows up in. Build it once per base and reuse it.
+    #[allow(dead_code)]
+    pub fn window_table(&self, window: usize) -> Vec<Self> {
+        assert!(
+            (1..=8).contains(&window),
+            "Window must be between 1 and 8 bits"
+        );
+        let mut table = vec![self.with_parts(FieldElement::one(), FieldElement::zero())];
+        for _ in 1..(1 << window) {
+            let next = table[table.len() - 1].multiply(self);
+            table.push(next);
+        }
+        table
+    }
+
+    /// Fixed-window exponentiation with a `window_table` of self. The exponent
+    /// is given as little-endian u64 limbs (exp[0] is the least significant one).
+    /// Every window costs `window` squarings and one multiplication, a zero
+    /// window included, so the sequence of operations doesn't depend on exp.
+    #[allow(dead_code)]
+    pub fn pow_fixed(&self, exp: &[u64], table: &[Self]) -> Self {
+        assert!(
+            table.len() >= 2 && table.len().is_power_of_two() && table[1] == *self,
+            "Table must come from window_table of this base"
+        );
+        let window = table.len().trailing_zeros() as usize;
+        let mut bits: Vec<bool> = exp
+            .iter()
+            .flat_map(|limb| (0..64).map(move |n| (limb >> n) & 1 == 1))
+            .collect();
+        // Zero high bits so that every window is full
+        bits.resize(bits.len().div_ceil(window) * window, false);
+
+        let mut result = table[0].clone();
+        for chunk in bits.rchunks_exact(window) {
+            for _ in 0..window {
+                result = result.multiply(&result);
+            }
+            let value = chunk
+                .iter()
+                .rev()
+                .fold(0, |acc, &bit| (acc << 1) | bit as usize);
+            result = result.multiply(&table[value]);
         }
+        result
+    }
+
+    /// Zero element, with the default non-residue
+    #[allow(dead_code)]
+    pub fn zero() -> Self {
+        FieldElementExt::new(FieldElement::zero(), FieldElement::zero())
+    }
+
+    /// One element, with the default non-residue
+    #[allow(dead_code)]
+    pub fn one() -> Self {
+        FieldElementExt::new(FieldElement::one(), FieldElement::zero())
+    }
+
+    /// Lifts a base field element into the default extension (imaginary part zero)
+    #[allow(dead_code)]
+    pub fn from_base(a: FieldElement) -> Self {
+        FieldElementExt::new(a, FieldElement::zero())
+    }
+}
+
+impl Neg for FieldElementExt {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        self.negate()
     }
 }
 
@@ -212,6 +821,15 @@ mod tests {
         assert_eq!(y.value, 0);
     }
 
+    #[test]
+    fn test_negate_all_elements() {
+        assert_eq!(FieldElement::zero().negate(), FieldElement::zero());
+        for value in 1..FieldElement::MODULUS {
+            let x = FieldElement::new(value);
+            assert_eq!(x.negate().value, FieldElement::MODULUS - value);
+        }
+    }
+
     #[test]
     fn test_inverse_basic() {
         // Test 1: Inverse of 1 should be 1 (1 * 1 ≡ 1 mod 101)
@@ -289,6 +907,22 @@ mod tests {
         assert_eq!(b8.value, 54);
     }
 
+    #[test]
+    fn test_rand_is_reproducible() {
+        use ark_std::rand::{rngs::StdRng, SeedableRng};
+
+        let mut rng1 = StdRng::seed_from_u64(42);
+        let mut rng2 = StdRng::seed_from_u64(42);
+        let run1: Vec<FieldElement> = (0..20).map(|_| FieldElement::rand(&mut rng1)).collect();
+        let run2: Vec<FieldElement> = (0..20).map(|_| FieldElement::rand(&mut rng2)).collect();
+        assert_eq!(run1, run2);
+        assert!(run1.iter().all(|x| x.value < FieldElement::MODULUS));
+
+        let nonzero = FieldElement::rand_nonzero(&mut rng1);
+        assert_eq!(nonzero, FieldElement::rand_nonzero(&mut rng2));
+        assert_ne!(nonzero, FieldElement::zero());
+    }
+
     #[test]
     fn test_pow_zero_base() {
         // Test: 0^0 = 1 (by convention in your implementation)
@@ -304,6 +938,276 @@ mod tests {
         let pow_2 = zero.pow(2);
         assert_eq!(pow_2.value, 0);
     }
+
+    #[test]
+    fn test_pow_limbs_matches_pow() {
+        let a = FieldElement::new(7);
+        for e in [0, 1, 2, 99, 100, 12345, u64::MAX] {
+            assert_eq!(a.pow_limbs(&[e, 0]), a.pow(e));
+        }
+        // a^(2^64) = (a^(2^32))^(2^32)
+        assert_eq!(a.pow_limbs(&[0, 1]), a.pow(1 << 32).pow(1 << 32));
+        assert_eq!(
+            a.pow_bits(&[true, false, true]), // 5
+            a.pow(5)
+        );
+    }
+
+    #[test]
+    fn test_inverse_fermat_matches_inverse() {
+        for value in 1..FieldElement::MODULUS {
+            let a = FieldElement::new(value);
+            assert_eq!(a.inverse_fermat(), a.inverse());
+        }
+        assert_eq!(FieldElement::zero().inverse_fermat(), FieldElement::zero());
+    }
+
+    #[test]
+    fn test_zero_one_traits() {
+        use ark_std::{One, Zero};
+
+        fn generic_sum<T: Zero + Clone>(values: &[T]) -> T {
+            values.iter().cloned().fold(T::zero(), |acc, x| acc + x)
+        }
+
+        let values: Vec<FieldElement> = (1..=20).map(FieldElement::new).collect();
+        let sum: FieldElement = values.iter().cloned().sum();
+        assert_eq!(sum, FieldElement::new(210)); // 210 mod 101 = 8
+        assert_eq!(generic_sum(&values), sum);
+
+        let product: FieldElement = (1..=5).map(FieldElement::new).product();
+        assert_eq!(product, FieldElement::new(120));
+        assert!(<FieldElement as One>::one().is_one());
+        assert!(Zero::is_zero(&FieldElement::new(101)));
+        assert!(
+            Vec::<FieldElement>::new().into_iter().sum::<FieldElement>() == FieldElement::zero()
+        );
+    }
+
+    #[test]
+    fn test_multiplicative_order() {
+        assert_eq!(FieldElement::new(2).multiplicative_order(), Some(100)); // primitive root
+        assert_eq!(FieldElement::one().multiplicative_order(), Some(1));
+        assert_eq!(FieldElement::new(100).multiplicative_order(), Some(2)); // -1
+        assert_eq!(FieldElement::new(10).multiplicative_order(), Some(4));
+        assert_eq!(FieldElement::zero().multiplicative_order(), None);
+    }
+
+    #[test]
+    fn test_legendre_and_sqrt() {
+        assert_eq!(FieldElement::zero().legendre(), 0);
+        assert_eq!(FieldElement::new(2).legendre(), -1); // primitive root
+        for value in 0..FieldElement::MODULUS {
+            let a = FieldElement::new(value);
+            let square = a.multiply(&a);
+            assert_ne!(square.legendre(), -1);
+            let root = square.sqrt().unwrap();
+            assert_eq!(root.multiply(&root), square);
+            assert!(root == a || root == a.negate());
+        }
+        assert_eq!(FieldElement::new(2).sqrt(), None);
+    }
+
+    #[test]
+    fn test_hex_round_trip() {
+        for value in [0, 1, 16, 100] {
+            let a = FieldElement::new(value);
+            assert_eq!(FieldElement::from_hex(&a.to_hex()), Ok(a));
+        }
+        assert_eq!(FieldElement::new(100).to_hex(), "0x64");
+        assert_eq!(FieldElement::from_hex("0X0a"), Ok(FieldElement::new(10)));
+        assert_eq!(FieldElement::from_hex("1f"), Ok(FieldElement::new(31)));
+    }
+
+    #[test]
+    fn test_from_hex_rejects_invalid() {
+        assert_eq!(
+            FieldElement::from_hex("0x65"), // 101
+            Err(ParseFieldElementError::OutOfRange)
+        );
+        assert_eq!(
+            FieldElement::from_hex("0x10000000000000000"),
+            Err(ParseFieldElementError::OutOfRange)
+        );
+        assert_eq!(
+            FieldElement::from_hex("0xg1"),
+            Err(ParseFieldElementError::InvalidDigit)
+        );
+        assert_eq!(
+            FieldElement::from_hex("0x"),
+            Err(ParseFieldElementError::InvalidDigit)
+        );
+    }
+
+    #[test]
+    fn test_be_bytes_round_trip() {
+        let a = FieldElement::new(100);
+        assert_eq!(a.to_be_bytes(), [0, 0, 0, 0, 0, 0, 0, 100]);
+        assert_eq!(FieldElement::from_be_bytes(a.to_be_bytes()), Ok(a));
+        assert_eq!(
+            FieldElement::from_be_bytes([0, 0, 0, 0, 0, 0, 0, 101]),
+            Err(ParseFieldElementError::OutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_from_conversions() {
+        assert_eq!(FieldElement::from(-1i64), FieldElement::new(100));
+        assert_eq!(FieldElement::from(-102i64), FieldElement::new(100));
+        assert_eq!(
+            FieldElement::from(i64::MIN).value,
+            i64::MIN.rem_euclid(101) as u64
+        );
+        assert_eq!(FieldElement::from(205i64), FieldElement::new(3));
+        assert_eq!(FieldElement::from(u32::MAX).value, u32::MAX as u64 % 101);
+        assert_eq!(FieldElement::from(202u32), FieldElement::zero());
+        assert_eq!(FieldElement::from(u64::MAX), FieldElement::new(u64::MAX));
+
+        assert_eq!(FieldElement::try_from("42"), Ok(FieldElement::new(42)));
+        assert_eq!(
+            FieldElement::try_from("101"),
+            Err(ParseFieldElementError::OutOfRange)
+        );
+        assert_eq!(
+            FieldElement::try_from("99999999999999999999999"),
+            Err(ParseFieldElementError::OutOfRange)
+        );
+        for invalid in ["", "-1", "0x10", "4 2"] {
+            assert_eq!(
+                FieldElement::try_from(invalid),
+                Err(ParseFieldElementError::InvalidDigit)
+            );
+        }
+    }
+
+    #[test]
+    fn test_roots_of_unity() {
+        assert_eq!(FieldElement::roots_of_unity(0), None);
+        assert_eq!(FieldElement::roots_of_unity(3), None);
+        for n in [1, 2, 4, 5, 10, 20, 25, 50, 100] {
+            let roots = FieldElement::roots_of_unity(n).unwrap();
+            assert_eq!(roots.len(), n as usize);
+            for (i, root) in roots.iter().enumerate() {
+                assert_eq!(root.pow(n), FieldElement::one());
+                assert!(!roots[..i].contains(root));
+            }
+            // The roots of x^n - 1 multiply to (-1)^(n+1)
+            let expected = if n % 2 == 1 {
+                FieldElement::one()
+            } else {
+                FieldElement::one().negate()
+            };
+            assert_eq!(roots.into_iter().product::<FieldElement>(), expected);
+        }
+    }
+
+    #[test]
+    fn test_two_adicity() {
+        assert_eq!(two_adicity(FieldElement::MODULUS), 2);
+        assert_eq!(two_adicity(97), 5); // 96 = 2^5·3
+        assert_eq!(two_adicity(257), 8);
+
+        assert_eq!(recommended_fft_prime(2), Some(5));
+        assert_eq!(recommended_fft_prime(5), Some(97));
+        assert_eq!(recommended_fft_prime(8), Some(257));
+        assert_eq!(recommended_fft_prime(9), Some(7681));
+        for s in 0..20 {
+            let p = recommended_fft_prime(s).unwrap();
+            assert!(is_prime(p));
+            assert!(two_adicity(p) >= s);
+        }
+
+        // 2^63 + 1 is a multiple of 3 and the next candidate overflows
+        assert_eq!(recommended_fft_prime(63), None);
+        assert_eq!(recommended_fft_prime(64), None);
+    }
+}
+
+#[cfg(test)]
+mod tests_props {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn element() -> impl Strategy<Value = FieldElement> {
+        (0..FieldElement::MODULUS).prop_map(FieldElement::new)
+    }
+
+    fn nonzero_element() -> impl Strategy<Value = FieldElement> {
+        (1..FieldElement::MODULUS).prop_map(FieldElement::new)
+    }
+
+    proptest! {
+        #[test]
+        fn add_is_associative_and_commutative(a in element(), b in element(), c in element()) {
+            prop_assert_eq!(
+                FieldElement::add(&a, &b).add(&c),
+                FieldElement::add(&a, &FieldElement::add(&b, &c))
+            );
+            prop_assert_eq!(FieldElement::add(&a, &b), FieldElement::add(&b, &a));
+        }
+
+        #[test]
+        fn mul_is_associative_and_commutative(a in element(), b in element(), c in element()) {
+            prop_assert_eq!(
+                a.multiply(&b).multiply(&c),
+                a.multiply(&b.multiply(&c))
+            );
+            prop_assert_eq!(a.multiply(&b), b.multiply(&a));
+        }
+
+        #[test]
+        fn mul_distributes_over_add(a in element(), b in element(), c in element()) {
+            prop_assert_eq!(
+                a.multiply(&FieldElement::add(&b, &c)),
+                FieldElement::add(&a.multiply(&b), &a.multiply(&c))
+            );
+        }
+
+        #[test]
+        fn identities_and_inverses(a in element(), b in nonzero_element()) {
+            prop_assert_eq!(FieldElement::add(&a, &FieldElement::zero()), a.clone());
+            prop_assert_eq!(a.multiply(&FieldElement::one()), a.clone());
+            prop_assert_eq!(FieldElement::add(&a, &a.negate()), FieldElement::zero());
+            prop_assert_eq!(a.substract(&a), FieldElement::zero());
+            prop_assert_eq!(b.multiply(&b.inverse()), FieldElement::one());
+        }
+
+        #[test]
+        fn fermat_little_theorem(a in nonzero_element()) {
+            prop_assert_eq!(a.pow(FieldElement::MODULUS - 1), FieldElement::one());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests_scalar {
+    use super::*;
+
+    #[test]
+    fn test_scalar_wraps_at_subgroup_order() {
+        let a = Scalar::new(10);
+        let b = Scalar::new(9);
+        assert_eq!(a.add(&b).value, 2); // 19 mod 17
+        assert_eq!(a.multiply(&b).value, 5); // 90 mod 17
+        assert_eq!(Scalar::new(17), Scalar::zero());
+
+        // The base field still wraps at 101
+        let fa = FieldElement::new(10);
+        let fb = FieldElement::new(9);
+        assert_eq!(FieldElement::add(&fa, &fb).value, 19);
+        assert_eq!(fa.multiply(&fb).value, 90);
+        assert_eq!(FieldElement::new(101), FieldElement::zero());
+    }
+
+    #[test]
+    fn test_scalar_inverse_and_negate() {
+        for v in 1..Scalar::MODULUS {
+            let a = Scalar::new(v);
+            assert_eq!(a.multiply(&a.inverse()), Scalar::one());
+            assert_eq!(a.add(&a.negate()), Scalar::zero());
+        }
+        assert_eq!(Scalar::new(3).pow(16), Scalar::one()); // Fermat
+    }
 }
 
 #[cfg(test)]
@@ -324,4 +1228,108 @@ mod tests_ext {
         assert_eq!(prod.a.value, 33);
         assert_eq!(prod.b.value, 63);
     }
+
+    #[test]
+    fn test_field_ext_inverse() {
+        let a = FieldElementExt::new(FieldElement::new(50), FieldElement::new(60));
+        assert_eq!(a.multiply(&a.inverse()), FieldElementExt::one());
+        let b = FieldElementExt::new(FieldElement::zero(), FieldElement::new(31));
+        assert_eq!(b.divide(&b), FieldElementExt::one());
+    }
+
+    #[test]
+    fn test_field_ext_pow() {
+        let a = FieldElementExt::new(FieldElement::new(50), FieldElement::new(60));
+        assert_eq!(a.pow(0), FieldElementExt::one());
+        assert_eq!(a.pow(1), a);
+        assert_eq!(a.pow(3), a.multiply(&a).multiply(&a));
+        // The multiplicative group of F_101^2 has order 101^2 - 1
+        assert_eq!(a.pow(101 * 101 - 1), FieldElementExt::one());
+    }
+
+    #[test]
+    fn test_field_ext_custom_nonresidue() {
+        // u^2 = -3, also a non-residue mod 101
+        let nr = FieldElement::new(101 - 3);
+        let a = FieldElementExt::with_nonresidue(
+            FieldElement::new(2),
+            FieldElement::new(5),
+            nr.clone(),
+        );
+        let b = FieldElementExt::with_nonresidue(
+            FieldElement::new(7),
+            FieldElement::new(3),
+            nr.clone(),
+        );
+        // (2 + 5u)(7 + 3u) = 14 + 15u^2 + (6 + 35)u = (14 - 45) + 41u = 70 + 41u
+        let prod = a.multiply(&b);
+        assert_eq!(prod.a.value, 70);
+        assert_eq!(prod.b.value, 41);
+        assert_eq!(prod.nonresidue, nr);
+        // u·u = -3
+        let u =
+            FieldElementExt::with_nonresidue(FieldElement::zero(), FieldElement::one(), nr.clone());
+        assert_eq!(u.multiply(&u).a, nr);
+        let one = FieldElementExt::with_nonresidue(FieldElement::one(), FieldElement::zero(), nr);
+        assert_eq!(a.multiply(&a.inverse()), one);
+        assert_eq!(a.pow(101 * 101 - 1), one);
+    }
+
+    #[test]
+    #[should_panic(expected = "u^2 must be a quadratic non-residue")]
+    fn test_field_ext_rejects_square_nonresidue() {
+        // -5 = 46^2 (mod 101), so u^2 + 5 factors and the quotient isn't a field
+        FieldElementExt::with_nonresidue(
+            FieldElement::one(),
+            FieldElement::one(),
+            FieldElement::new(101 - 5),
+        );
+    }
+
+    #[test]
+    fn test_field_ext_conjugate_and_norm() {
+        let x = FieldElementExt::new(FieldElement::new(50), FieldElement::new(60));
+        assert_eq!(
+            x.conjugate(),
+            FieldElementExt::new(FieldElement::new(50), FieldElement::new(41))
+        );
+        // 50^2 + 2·60^2 = 9700 = 4 (mod 101)
+        assert_eq!(x.norm(), FieldElement::new(4));
+        let product = x.multiply(&x.conjugate());
+        assert_eq!(product.b, FieldElement::zero());
+        assert_eq!(product, FieldElementExt::from_base(x.norm()));
+        // The conjugate is the Frobenius x^101
+        assert_eq!(x.pow(101), x.conjugate());
+    }
+
+    #[test]
+    fn test_field_ext_pow_fixed() {
+        let exponents = [0, 1, 2, 17, 600, 10199, 123456789];
+        for (a, b) in [(50, 60), (1, 0), (0, 31), (7, 99)] {
+            let x = FieldElementExt::new(FieldElement::new(a), FieldElement::new(b));
+            for window in [1, 3, 4] {
+                let table = x.window_table(window);
+                assert_eq!(table.len(), 1 << window);
+                assert_eq!(table[0], FieldElementExt::one());
+                for exp in exponents {
+                    assert_eq!(x.pow_fixed(&[exp], &table), x.pow(exp));
+                }
+                // 2^64 + 5, reduced by the group order 101^2 - 1 for pow
+                let wide = ((1u128 << 64) + 5) % (101 * 101 - 1);
+                assert_eq!(x.pow_fixed(&[5, 1], &table), x.pow(wide as u64));
+            }
+        }
+    }
+
+    #[test]
+    fn test_field_ext_negate() {
+        let x = FieldElementExt::new(FieldElement::new(50), FieldElement::new(60));
+        assert_eq!(
+            x.negate(),
+            FieldElementExt::new(FieldElement::new(51), FieldElement::new(41))
+        );
+        assert_eq!(x.add(&x.negate()), FieldElementExt::zero());
+        assert_eq!(-x.clone(), FieldElementExt::zero().substract(&x));
+        assert_eq!(FieldElementExt::zero().negate(), FieldElementExt::zero());
+    }
 }
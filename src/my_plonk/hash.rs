@@ -0,0 +1,57 @@
+use super::field::FieldElement;
+use sha2::{Digest, Sha256};
+
+/*
+    Hashing into F_101 with SHA-256. There's no transcript yet, so this is
+    the hash the Fiat-Shamir challenges should be derived with.
+
+    The label is length-prefixed so (label, data) pairs can't collide by
+    moving bytes from one to the other. A counter is appended and bumped
+    whenever the digest lands above the largest multiple of 101 that fits in
+    a u64, so every field element is equally likely.
+*/
+
+/// H(len(label) || label || data || counter) reduced into F_101
+#[allow(dead_code)]
+pub fn hash_to_field(label: &[u8], data: &[u8]) -> FieldElement {
+    let limit = u64::MAX - u64::MAX % FieldElement::MODULUS;
+    let mut counter: u64 = 0;
+    loop {
+        let digest = Sha256::new()
+            .chain_update((label.len() as u64).to_be_bytes())
+            .chain_update(label)
+            .chain_update(data)
+            .chain_update(counter.to_be_bytes())
+            .finalize();
+        let mut head = [0u8; 8];
+        head.copy_from_slice(&digest[..8]);
+        let value = u64::from_be_bytes(head);
+        if value < limit {
+            return FieldElement::new(value);
+        }
+        counter += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hash_to_field_is_deterministic() {
+        let a = hash_to_field(b"challenge", b"some data");
+        let b = hash_to_field(b"challenge", b"some data");
+        assert_eq!(a, b);
+        assert!(a.value < FieldElement::MODULUS);
+    }
+
+    #[test]
+    fn test_hash_to_field_domain_separation() {
+        assert_ne!(
+            hash_to_field(b"alpha", b"some data"),
+            hash_to_field(b"beta", b"some data")
+        );
+        // Moving bytes between label and data changes the output too
+        assert_ne!(hash_to_field(b"ab", b"c"), hash_to_field(b"a", b"bc"));
+    }
+}
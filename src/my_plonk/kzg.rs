@@ -1,9 +1,13 @@
+use super::domain::EvaluationDomain;
 use super::elliptic_curve::{EllipticCurve, Point, PointExt};
-use super::field::FieldElement;
-use super::polynomial::Polynomial;
-use super::toy_pairing::Pairing;
+use super::field::{FieldElement, Scalar};
+use super::hash::hash_to_field;
+use super::polynomial::{Polynomial, ScalarPolynomial};
+use super::toy_pairing::{Pairing, PairingCheck};
 use ark_std::rand;
-use rand::Rng;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::sync::OnceLock;
 
 /*
     Preferred parameters:
@@ -11,79 +15,325 @@ use rand::Rng;
     - Generator 1 (G1): (1, 2)
     - Finite field: mod 101
     - EC subgroup order: 17 (there are 17 valid EC points created from G)
+
+    Commitments are only homomorphic mod 17, so polynomial coefficients
+    (and evaluation points) are read as scalars of F_17.
 */
 
+#[derive(Debug, PartialEq)]
+pub enum KzgError {
+    DegreeTooLarge,  // more coefficients than powers of tau in the setup
+    EmptyPolynomial, // no coefficients at all
+    PointNotOnCurve, // commitment or proof is not a valid curve point
+    PairingMismatch, // the pairing check of an opening failed
+    DegreeTooSmall,  // extending the setup to a lower degree than it has
+    WrongTau,        // the supplied tau doesn't match the setup
+    WrongEvalCount,  // not one evaluation per domain point
+}
+
+#[allow(clippy::upper_case_acronyms)]
 #[derive(Debug)]
 pub struct KZG {
     curve: EllipticCurve,
-    setup_g1: Vec<Point>,    // [1]G, [tau]G, ...
-    setup_g2: Vec<PointExt>, // [1]H, [tau]H
+    setup_g1: Vec<Point>,               // [1]G, [tau]G, ...
+    setup_g2: Vec<PointExt>,            // [1]H, [tau]H
+    setup_lagrange: Vec<Point>,         // [L_0(tau)]G, [L_1(tau)]G, ... once setup_lagrange runs
+    domain: OnceLock<EvaluationDomain>, // built by the first call to domain()
 }
 
 impl KZG {
+    /// Trusted setup with a fresh random tau
     #[allow(dead_code)]
     pub fn new(degree: usize) -> Self {
+        Self::new_with_rng(degree, &mut rand::thread_rng())
+    }
+
+    /// Trusted setup with tau drawn from the given RNG (e.g. a custom CSPRNG)
+    #[allow(dead_code)]
+    pub fn new_with_rng<R: Rng>(degree: usize, rng: &mut R) -> Self {
+        Self::new_with_tau(degree, FieldElement::new(rng.gen_range(1..Scalar::MODULUS)))
+    }
+
+    /// Trusted setup with tau drawn from a seeded RNG, reproducible across runs
+    #[allow(dead_code)]
+    pub fn new_seeded(degree: usize, seed: u64) -> Self {
+        Self::new_with_rng(degree, &mut StdRng::seed_from_u64(seed))
+    }
+
+    /// Trusted setup with an explicit secret. Only for tests: anyone who
+    /// knows tau can forge openings.
+    #[allow(dead_code)]
+    pub fn new_with_tau(degree: usize, tau: FieldElement) -> Self {
+        let tau_scalar = Scalar::from_field_element(&tau);
+        assert!(tau_scalar != Scalar::zero(), "tau must be nonzero mod 17");
         let curve = EllipticCurve::new();
-        let mut rng = rand::thread_rng();
-        let tau = FieldElement::new(rng.gen_range(1..FieldElement::MODULUS));
         let g = curve.generator_g1();
         let h = curve.generator_g2();
 
-        let mut setup_g1 = vec![g.clone()];
-        for n in 1..=degree {
-            let tau_pow_n = tau.pow(n as u64);
-            let point = g.scalar_mul(&curve, tau_pow_n);
-            setup_g1.push(point);
-        }
+        let setup_g1 = curve.scalar_mul_powers(&g, tau, degree);
 
         let mut setup_g2 = vec![h.clone()];
-        let tau_h = h.scalar_mul(&curve, tau);
+        let tau_h = h.scalar_mul(&curve, tau_scalar);
         setup_g2.push(tau_h);
 
         KZG {
             curve,
             setup_g1,
             setup_g2,
+            setup_lagrange: vec![],
+            domain: OnceLock::new(),
         }
     }
 
+    /// Appends [tau^n]G up to new_degree. The original tau must be supplied
+    /// again, it's checked against [tau]H in the setup.
     #[allow(dead_code)]
-    pub fn commit(&self, poly: &Polynomial) -> Point {
+    pub fn extend(&mut self, new_degree: usize, tau: FieldElement) -> Result<(), KzgError> {
+        let degree = self.setup_g1.len() - 1;
+        if new_degree < degree {
+            return Err(KzgError::DegreeTooSmall);
+        }
+        let tau_h = self.setup_g2[0].scalar_mul(&self.curve, Scalar::from_field_element(&tau));
+        if tau_h != self.setup_g2[1] {
+            return Err(KzgError::WrongTau);
+        }
+        let last = &self.setup_g1[degree];
+        let new_powers = self.curve.scalar_mul_powers(last, tau, new_degree - degree);
+        self.setup_g1.extend(new_powers.into_iter().skip(1));
+        self.domain = OnceLock::new(); // the degree changed
+        Ok(())
+    }
+
+    /// Largest evaluation domain the setup can commit over: a polynomial
+    /// interpolated on it has at most as many coefficients as there are powers
+    /// of tau. Sizes must divide 100, so this is the largest such divisor not
+    /// above the number of powers. Built on first use and reused afterwards.
+    #[allow(dead_code)]
+    pub fn domain(&self) -> &EvaluationDomain {
+        self.domain.get_or_init(|| {
+            (1..=self.setup_g1.len())
+                .rev()
+                .find_map(EvaluationDomain::new)
+                .expect("Size 1 is always a domain")
+        })
+    }
+
+    /// Domain points read as scalars. The F_101 roots of unity stay distinct
+    /// mod 17 for the small domains, larger ones can't fit in F_17.
+    fn scalar_points(domain: &EvaluationDomain) -> Vec<Scalar> {
+        let xs: Vec<Scalar> = domain
+            .elements
+            .iter()
+            .map(Scalar::from_field_element)
+            .collect();
+        for (i, xi) in xs.iter().enumerate() {
+            assert!(
+                !xs[..i].contains(xi),
+                "Domain points collide in the scalar field"
+            );
+        }
+        xs
+    }
+
+    /// Interpolates evals[i] at the i-th domain point, read as a scalar.
+    /// Everything is mod 17, so committing the result agrees with
+    /// `commit_lagrange` on the same evaluations.
+    #[allow(dead_code)]
+    pub fn interpolate(evals: &[FieldElement], domain: &EvaluationDomain) -> ScalarPolynomial {
+        let points: Vec<(Scalar, Scalar)> = Self::scalar_points(domain)
+            .into_iter()
+            .zip(evals.iter().map(Scalar::from_field_element))
+            .collect();
+        ScalarPolynomial::lagrange_interpolate(&points)
+    }
+
+    /// Precomputes the Lagrange-basis SRS [L_i(tau)]G for the domain, so that a
+    /// polynomial given by its evaluations is committed with a single MSM.
+    /// The basis is built in the scalar field over the domain points read mod 17.
+    #[allow(dead_code)]
+    pub fn setup_lagrange(&mut self, domain: &EvaluationDomain) {
+        assert!(
+            domain.size <= self.setup_g1.len(),
+            "Domain of size {} needs an SRS of degree {}",
+            domain.size,
+            domain.size - 1
+        );
+        let xs = Self::scalar_points(domain);
+        self.setup_lagrange = (0..xs.len())
+            .map(|i| {
+                let points: Vec<(Scalar, Scalar)> = xs
+                    .iter()
+                    .enumerate()
+                    .map(|(j, xj)| {
+                        let yj = if i == j {
+                            Scalar::one()
+                        } else {
+                            Scalar::zero()
+                        };
+                        (xj.clone(), yj)
+                    })
+                    .collect();
+                let basis = ScalarPolynomial::lagrange_interpolate(&points);
+                self.commit_scalars(&basis.coeffs)
+            })
+            .collect();
+    }
+
+    /// Commits to the polynomial taking evals[i] at the i-th domain point
+    #[allow(dead_code)]
+    pub fn commit_lagrange(&self, evals: &[FieldElement]) -> Point {
+        assert_eq!(
+            evals.len(),
+            self.setup_lagrange.len(),
+            "Need one evaluation per domain point (call setup_lagrange first)"
+        );
         let mut commitment = self.curve.infinity();
-        let degree = poly.coeffs.len() - 1;
-        for i in 0..=degree {
-            let coeff = poly.coeffs[i].clone();
-            let power = &self.setup_g1[i];
-            let scaled_power = power.scalar_mul(&self.curve, coeff);
-            commitment = self.curve.add(&commitment, &scaled_power);
+        for (eval, basis) in evals.iter().zip(&self.setup_lagrange) {
+            let scaled_basis = basis.scalar_mul(&self.curve, Scalar::from_field_element(eval));
+            self.curve.add_assign(&mut commitment, &scaled_basis);
         }
         commitment
     }
 
+    /// Commits to the polynomial with the given evaluations over the domain
+    /// (e.g. a quotient computed pointwise), interpolating in the scalar field
+    /// like `interpolate`. `domain()` is the largest domain the setup fits.
     #[allow(dead_code)]
-    pub fn prove(&self, poly: &Polynomial, z: FieldElement) -> (FieldElement, Point) {
-        let y = poly.evaluate(z.clone());
-        let mut q_coeffs = vec![FieldElement::new(0); poly.coeffs.len() - 1];
-        let mut remainder = poly.coeffs.clone();
-        remainder[0] = remainder[0].substract(&y);
-        for i in (1..remainder.len()).rev() {
-            q_coeffs[i - 1] = remainder[i].clone();
-            remainder[i] = FieldElement::new(0);
-            remainder[i - 1] = remainder[i - 1].substract(&q_coeffs[i - 1].multiply(&z));
+    pub fn commit_quotient_from_evals(
+        &self,
+        evals: &[FieldElement],
+        domain: &EvaluationDomain,
+    ) -> Result<Point, KzgError> {
+        if evals.len() != domain.size {
+            return Err(KzgError::WrongEvalCount);
+        }
+        if domain.size > self.setup_g1.len() {
+            return Err(KzgError::DegreeTooLarge);
         }
-        let q_poly = Polynomial { coeffs: q_coeffs };
+        self.commit_scalar_polynomial(&Self::interpolate(evals, domain))
+    }
 
-        let mut proof = self.curve.infinity();
-        let degree = q_poly.coeffs.len() - 1;
-        for i in 0..=degree {
-            let coeff = q_poly.coeffs[i].clone();
-            let power = &self.setup_g1[i];
-            let scaled_power = power.scalar_mul(&self.curve, coeff);
-            proof = self.curve.add(&proof, &scaled_power);
+    /// ∑ [c_i]·[tau^i]G
+    fn commit_scalars(&self, coeffs: &[Scalar]) -> Point {
+        let mut commitment = self.curve.infinity();
+        for (coeff, power) in coeffs.iter().zip(&self.setup_g1) {
+            let scaled_power = power.scalar_mul(&self.curve, coeff.clone());
+            self.curve.add_assign(&mut commitment, &scaled_power);
+        }
+        commitment
+    }
+
+    #[allow(dead_code)]
+    pub fn commit(&self, poly: &Polynomial) -> Result<Point, KzgError> {
+        self.debug_assert_fits(poly);
+        let coeffs = self.check_polynomial(poly)?;
+        Ok(self.commit_scalars(&coeffs))
+    }
+
+    /// Same as `commit` for a polynomial that is already over the scalar field
+    #[allow(dead_code)]
+    pub fn commit_scalar_polynomial(&self, poly: &ScalarPolynomial) -> Result<Point, KzgError> {
+        if poly.coeffs.len() > self.setup_g1.len() {
+            return Err(KzgError::DegreeTooLarge);
+        }
+        Ok(self.commit_scalars(&poly.coeffs))
+    }
+
+    /// An over-degree polynomial is a caller bug: debug builds panic right away,
+    /// release builds get DegreeTooLarge from `check_polynomial`
+    fn debug_assert_fits(&self, poly: &Polynomial) {
+        debug_assert!(
+            poly.coeffs.len() <= self.setup_g1.len(),
+            "Polynomial of degree {} doesn't fit in an SRS of degree {}",
+            poly.coeffs.len().saturating_sub(1),
+            self.setup_g1.len() - 1
+        );
+    }
+
+    /// Reads the coefficients as scalars, checking there is at least one and
+    /// that the setup is large enough
+    fn check_polynomial(&self, poly: &Polynomial) -> Result<Vec<Scalar>, KzgError> {
+        if poly.coeffs.is_empty() {
+            return Err(KzgError::EmptyPolynomial);
+        }
+        if poly.coeffs.len() > self.setup_g1.len() {
+            return Err(KzgError::DegreeTooLarge);
         }
-        (y, proof)
+        Ok(poly.coeffs.iter().map(Scalar::from_field_element).collect())
+    }
+
+    /// Opens the polynomial at z. The evaluation and the quotient are computed
+    /// over the scalar field F_17, the field the commitment is homomorphic over,
+    /// so coefficients are reduced mod 17 just like in `commit`.
+    #[allow(dead_code)]
+    pub fn prove(
+        &self,
+        poly: &Polynomial,
+        z: FieldElement,
+    ) -> Result<(FieldElement, Point), KzgError> {
+        self.debug_assert_fits(poly);
+        let coeffs = self.check_polynomial(poly)?;
+        let z = Scalar::from_field_element(&z);
+        let y = Self::evaluate_scalars(&coeffs, &z);
+        let proof = self.open_scalars(coeffs, &z, &y);
+        Ok((FieldElement::new(y.value), proof))
+    }
+
+    /// [q(τ)]G1 for q(x) = (p(x) - y) / (x - z). The division has to be exact;
+    /// a remainder means y isn't p(z) and the proof would be silently wrong.
+    fn open_scalars(&self, mut coeffs: Vec<Scalar>, z: &Scalar, y: &Scalar) -> Point {
+        coeffs[0] = coeffs[0].substract(y);
+        let (q_coeffs, remainder) = Self::divide_by_linear(coeffs, z);
+        debug_assert!(
+            remainder == Scalar::zero(),
+            "p(x) - y isn't divisible by (x - z), remainder {}",
+            remainder.value
+        );
+        self.commit_scalars(&q_coeffs)
     }
 
+    /// Returns y = p(z) and the witness q(x) = (p(x) - y) / (x - z) that `prove`
+    /// commits to. Like `prove`, p is read mod 17 and the division is done
+    /// in the scalar field, so q·(x - z) + y gives back p as a scalar polynomial.
+    #[allow(dead_code)]
+    pub fn compute_witness_polynomial(
+        &self,
+        poly: &Polynomial,
+        z: FieldElement,
+    ) -> (FieldElement, ScalarPolynomial) {
+        let poly = ScalarPolynomial::from(poly);
+        let z = Scalar::from_field_element(&z);
+        let y = poly.evaluate(&z);
+        let x_minus_z = ScalarPolynomial::new(vec![z.negate(), Scalar::one()]);
+        let (witness, _) = (poly - ScalarPolynomial::new(vec![y.clone()])).divide(&x_minus_z);
+        (FieldElement::new(y.value), witness)
+    }
+
+    /// p(z) by Horner's rule
+    fn evaluate_scalars(coeffs: &[Scalar], z: &Scalar) -> Scalar {
+        coeffs
+            .iter()
+            .rev()
+            .fold(Scalar::zero(), |acc, coeff| acc.multiply(z).add(coeff))
+    }
+
+    /// Synthetic division of p(x) by (x - z), returns (quotient, remainder)
+    fn divide_by_linear(coeffs: Vec<Scalar>, z: &Scalar) -> (Vec<Scalar>, Scalar) {
+        if coeffs.is_empty() {
+            return (vec![], Scalar::zero());
+        }
+        let mut q_coeffs = vec![Scalar::zero(); coeffs.len() - 1];
+        let mut remainder = coeffs;
+        for i in (1..remainder.len()).rev() {
+            q_coeffs[i - 1] = remainder[i].clone();
+            remainder[i] = Scalar::zero();
+            remainder[i - 1] = remainder[i - 1].add(&q_coeffs[i - 1].multiply(z));
+        }
+        (q_coeffs, remainder[0].clone())
+    }
+
+    /// Checks the opening with the pairing equation
+    ///     e(C - [y]G1, G2) == e(proof, [tau]G2 - [z]G2)
     #[allow(dead_code)]
     pub fn verify(
         &self,
@@ -91,45 +341,171 @@ impl KZG {
         z: FieldElement,
         y: FieldElement,
         proof: &Point,
-    ) -> bool {
+    ) -> Result<(), KzgError> {
+        if !self.curve.is_on_curve(commitment) || !self.curve.is_on_curve(proof) {
+            return Err(KzgError::PointNotOnCurve);
+        }
         let g1 = &self.setup_g1[0];
         let g2 = &self.setup_g2[0];
         let tau_g2 = &self.setup_g2[1];
-        let y_g1 = g1.scalar_mul(&self.curve, y);
+        let y_g1 = g1.scalar_mul(&self.curve, Scalar::from_field_element(&y));
         let commitment_minus_y_g1 = self.curve.add(
             commitment,
-            &y_g1.scalar_mul(&self.curve, FieldElement::new(FieldElement::MODULUS - 1)),
+            &y_g1.scalar_mul(&self.curve, Scalar::new(Scalar::MODULUS - 1)),
         );
-        let z_g2 = g2.scalar_mul(&self.curve, z);
+        let z_g2 = g2.scalar_mul(&self.curve, Scalar::from_field_element(&z));
         let tau_g2_minus_z_g2 = self.curve.add_ext(
             tau_g2,
-            &z_g2.scalar_mul(&self.curve, FieldElement::new(FieldElement::MODULUS - 1)),
+            &z_g2.scalar_mul(&self.curve, Scalar::new(Scalar::MODULUS - 1)),
         );
 
-        let map_to_scalar = |p: &Point| {
-            if p.is_infinity {
-                FieldElement::new(1)
-            } else {
-                p.x.clone()
+        let left = Pairing::tate(&commitment_minus_y_g1, g2, &self.curve);
+        let right = Pairing::tate(proof, &tau_g2_minus_z_g2, &self.curve);
+        if left != right {
+            return Err(KzgError::PairingMismatch);
+        }
+        Ok(())
+    }
+
+    /// Folds openings (y_i, proof_i) at the same z into an opening of ∑ c_i·p_i
+    /// at z: the claimed value is ∑ c_i·y_i and the proof is ∑ [c_i]proof_i.
+    /// Verify it against the commitment ∑ [c_i]C_i.
+    #[allow(dead_code)]
+    pub fn combine_proofs(
+        &self,
+        proofs: &[(Point, FieldElement)],
+        coeffs: &[FieldElement],
+    ) -> (FieldElement, Point) {
+        assert_eq!(proofs.len(), coeffs.len(), "Need one coefficient per proof");
+        let mut value = Scalar::zero();
+        let mut proof = self.curve.infinity();
+        for ((proof_i, y_i), c_i) in proofs.iter().zip(coeffs) {
+            let c_i = Scalar::from_field_element(c_i);
+            value = value.add(&c_i.multiply(&Scalar::from_field_element(y_i)));
+            self.curve
+                .add_assign(&mut proof, &proof_i.scalar_mul(&self.curve, c_i));
+        }
+        (FieldElement::new(value.value), proof)
+    }
+
+    /// Checks the SRS is well-formed: it starts at the generators, every point
+    /// is on the curve and consecutive powers are related by the same tau,
+    ///     e([tau^i]G, H) == e([tau^(i-1)]G, [tau]H)
+    #[allow(dead_code)]
+    pub fn verify_srs(&self) -> bool {
+        if self.setup_g1.first() != Some(&self.curve.generator_g1())
+            || self.setup_g2.first() != Some(&self.curve.generator_g2())
+            || self.setup_g2.len() != 2
+            || !self.setup_g1.iter().all(|p| self.curve.is_on_curve(p))
+        {
+            return false;
+        }
+        let h = &self.setup_g2[0];
+        let tau_h = &self.setup_g2[1];
+        self.setup_g1.windows(2).all(|pair| {
+            Pairing::tate(&pair[1], h, &self.curve) == Pairing::tate(&pair[0], tau_h, &self.curve)
+        })
+    }
+
+    /// Checks many openings (commitment, z, y, proof) with two pairings in
+    /// total, folding them with powers of r. r has to be random and chosen
+    /// after the openings, and it must be nonzero mod 17.
+    #[allow(dead_code)]
+    pub fn verify_many(
+        &self,
+        openings: &[(Point, FieldElement, FieldElement, Point)],
+        r: FieldElement,
+    ) -> bool {
+        self.fold_and_check(openings, Scalar::from_field_element(&r))
+    }
+
+    /// Batch verification of openings (commitment, z, y, proof) at distinct
+    /// points z_i with two pairings in total. Each check
+    ///     e(C_i - [y_i]G1, G2) == e(π_i, [τ]G2 - [z_i]G2)
+    /// is rewritten so that z_i moves to the G1 side,
+    ///     e(C_i - [y_i]G1 + [z_i]π_i, G2) == e(π_i, [τ]G2)
+    /// and the checks are summed with weights r^i, leaving one pairing per
+    /// side. r must be chosen after the openings so it can't be picked to make
+    /// a bad opening cancel out against another one, e.g. `batch_challenge`.
+    #[allow(dead_code)]
+    pub fn batch_verify_distinct(
+        &self,
+        items: &[(Point, FieldElement, FieldElement, Point)],
+        r: FieldElement,
+    ) -> bool {
+        let on_curve = items.iter().all(|(commitment, _, _, proof)| {
+            self.curve.is_on_curve(commitment) && self.curve.is_on_curve(proof)
+        });
+        on_curve && self.fold_and_check(items, Scalar::from_field_element(&r))
+    }
+
+    /// Nonzero r mod 17 from a hash of every opening. Rehashes with a counter
+    /// when the hash reduces to 0 mod 17, since r = 0 would zero out every
+    /// weight but the first.
+    #[allow(dead_code)]
+    pub fn batch_challenge(items: &[(Point, FieldElement, FieldElement, Point)]) -> FieldElement {
+        let mut data = vec![];
+        for (commitment, z, y, proof) in items {
+            for point in [commitment, proof] {
+                data.extend(point.x.to_be_bytes());
+                data.extend(point.y.to_be_bytes());
+                data.push(point.is_infinity as u8);
             }
-        };
-        let map_to_scalar_ext = |p: &PointExt| {
-            if p.is_infinity {
-                FieldElement::new(1)
-            } else {
-                p.x.a.clone()
+            data.extend(z.to_be_bytes());
+            data.extend(y.to_be_bytes());
+        }
+        let mut counter: u64 = 0;
+        loop {
+            let mut input = data.clone();
+            input.extend(counter.to_be_bytes());
+            let r = hash_to_field(b"kzg batch", &input);
+            if Scalar::from_field_element(&r) != Scalar::zero() {
+                return r;
             }
-        };
+            counter += 1;
+        }
+    }
+
+    /// Folds the openings with weights r^i into a single pairing check.
+    /// Rejects r = 0, which would only check the first opening.
+    fn fold_and_check(
+        &self,
+        openings: &[(Point, FieldElement, FieldElement, Point)],
+        r: Scalar,
+    ) -> bool {
+        if r == Scalar::zero() {
+            return false;
+        }
+        let g1 = &self.setup_g1[0];
+        let mut r_power = Scalar::one();
+        let mut folded_left = self.curve.infinity();
+        let mut folded_proof = self.curve.infinity();
+        for (commitment, z, y, proof) in openings {
+            let y_g1 = g1.scalar_mul(&self.curve, Scalar::from_field_element(y));
+            let z_proof = proof.scalar_mul(&self.curve, Scalar::from_field_element(z));
+            let left = self.curve.add(
+                &self.curve.add(commitment, &z_proof),
+                &y_g1.scalar_mul(&self.curve, Scalar::new(Scalar::MODULUS - 1)),
+            );
+            folded_left = self
+                .curve
+                .add(&folded_left, &left.scalar_mul(&self.curve, r_power.clone()));
+            folded_proof = self.curve.add(
+                &folded_proof,
+                &proof.scalar_mul(&self.curve, r_power.clone()),
+            );
+            r_power = r_power.multiply(&r);
+        }
 
-        let left = Pairing::new(map_to_scalar(&commitment_minus_y_g1), map_to_scalar_ext(g2));
-        let right = Pairing::new(map_to_scalar(proof), map_to_scalar_ext(&tau_g2_minus_z_g2));
-        println!("Left: {:?}", left.e);
-        println!("Right: {:?}", right.e);
-        // the toy pairing lacks of bilinearity so it won't correctly compute anything...
-        // don't really want to deep dive into a pairing implementation, maybe in the future...
-        // for the ark plonk version I'll be using a proper pairing function and constructing a real
-        // world plonk (educational).
-        true
+        // e(left, G2)·e(-proof, [τ]G2) == 1
+        let minus_one = Scalar::new(Scalar::MODULUS - 1);
+        let mut check = PairingCheck::new(&self.curve);
+        check.add_term(folded_left, self.setup_g2[0].clone());
+        check.add_term(
+            folded_proof.scalar_mul(&self.curve, minus_one),
+            self.setup_g2[1].clone(),
+        );
+        check.verify()
     }
 }
 
@@ -141,16 +517,466 @@ mod tests {
     fn test_kzg() {
         let kzg = KZG::new(2);
         let poly = Polynomial::new(vec![FieldElement::one(), FieldElement::new(2)]); // 1 + 2x
-        let commitment = kzg.commit(&poly);
+        let commitment = kzg.commit(&poly).unwrap();
         let z = FieldElement::new(3);
-        let (y, proof) = kzg.prove(&poly, z.clone());
+        let (y, proof) = kzg.prove(&poly, z.clone()).unwrap();
         assert_eq!(
             proof,
             kzg.curve
                 .generator_g1()
-                .scalar_mul(&kzg.curve, FieldElement::new(2))
+                .scalar_mul(&kzg.curve, Scalar::new(2))
         );
         assert_eq!(y.value, 7); // 1 + 2*3 = 7
-        assert!(kzg.verify(&commitment, z, y, &proof));
+        assert_eq!(kzg.verify(&commitment, z, y, &proof), Ok(()));
+    }
+
+    #[test]
+    fn test_kzg_verify_rejects_wrong_evaluation() {
+        let kzg = KZG::new(3);
+        // 4 + 3x + 5x^2 + x^3
+        let poly = Polynomial::new(vec![
+            FieldElement::new(4),
+            FieldElement::new(3),
+            FieldElement::new(5),
+            FieldElement::one(),
+        ]);
+        let commitment = kzg.commit(&poly).unwrap();
+        let z = FieldElement::new(6);
+        let (y, proof) = kzg.prove(&poly, z.clone()).unwrap();
+        assert_eq!(
+            kzg.verify(&commitment, z.clone(), y.clone(), &proof),
+            Ok(())
+        );
+
+        let wrong_y = y.add(&FieldElement::one());
+        assert_eq!(
+            kzg.verify(&commitment, z, wrong_y, &proof),
+            Err(KzgError::PairingMismatch)
+        );
+    }
+
+    /// Openings of 1 + 2x at 3, 4 + 3x + 5x^2 + x^3 at 6 and 7x^2 at 0
+    fn batch_openings(kzg: &KZG) -> Vec<(Point, FieldElement, FieldElement, Point)> {
+        let polys = [vec![1, 2], vec![4, 3, 5, 1], vec![0, 0, 7]];
+        polys
+            .iter()
+            .zip([3, 6, 0])
+            .map(|(coeffs, z)| {
+                let poly = Polynomial::new(coeffs.iter().map(|c| FieldElement::new(*c)).collect());
+                let z = FieldElement::new(z);
+                let (y, proof) = kzg.prove(&poly, z.clone()).unwrap();
+                (kzg.commit(&poly).unwrap(), z, y, proof)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_verify_many() {
+        let kzg = KZG::new_seeded(3, 1839);
+        let mut openings = batch_openings(&kzg);
+        let r = FieldElement::new(5);
+        assert!(kzg.verify_many(&openings, r.clone()));
+
+        // Opened at z = 0 != tau, so shifting the proof by G can't cancel out
+        let tampered = &mut openings[2].3;
+        *tampered = kzg.curve.add(tampered, &kzg.curve.generator_g1());
+        assert!(!kzg.verify_many(&openings, r));
+    }
+
+    #[test]
+    fn test_batch_verify_distinct() {
+        let kzg = KZG::new_seeded(3, 1909);
+        let mut items = batch_openings(&kzg);
+        let r = KZG::batch_challenge(&items);
+        assert!(kzg.batch_verify_distinct(&items, r));
+        for r in [2, 7, 11] {
+            assert!(kzg.batch_verify_distinct(&items, FieldElement::new(r)));
+        }
+
+        // A wrong evaluation at one of the points
+        let y = items[1].2.clone();
+        items[1].2 = FieldElement::add(&y, &FieldElement::one());
+        let r = KZG::batch_challenge(&items);
+        assert!(!kzg.batch_verify_distinct(&items, r));
+        items[1].2 = y;
+
+        // Opened at z = 0 != tau, so shifting the proof by G can't cancel out
+        let proof = items[2].3.clone();
+        items[2].3 = kzg.curve.add(&proof, &kzg.curve.generator_g1());
+        let r = KZG::batch_challenge(&items);
+        assert!(!kzg.batch_verify_distinct(&items, r));
+        items[2].3 = proof;
+
+        // A proof off the curve
+        items[0].3 = Point {
+            x: FieldElement::new(1),
+            y: FieldElement::new(1),
+            is_infinity: false,
+        };
+        assert!(!kzg.batch_verify_distinct(&items, FieldElement::new(7)));
+    }
+
+    #[test]
+    fn test_batch_verify_rejects_zero_weight() {
+        // With r = 17 = 0 mod 17 only the first opening would be weighted,
+        // so the wrong evaluation in the second one would go through
+        let kzg = KZG::new_seeded(3, 1909);
+        let mut items = batch_openings(&kzg);
+        let y = items[1].2.clone();
+        items[1].2 = FieldElement::add(&y, &FieldElement::one());
+        assert!(!kzg.batch_verify_distinct(&items, FieldElement::new(17)));
+        assert!(!kzg.batch_verify_distinct(&items, FieldElement::new(7)));
+
+        let r = KZG::batch_challenge(&items);
+        assert_ne!(Scalar::from_field_element(&r), Scalar::zero());
+        assert_eq!(r, KZG::batch_challenge(&items));
+    }
+
+    #[test]
+    fn test_kzg_errors() {
+        let kzg = KZG::new(1);
+        let empty = Polynomial { coeffs: vec![] };
+        assert_eq!(kzg.commit(&empty), Err(KzgError::EmptyPolynomial));
+        assert_eq!(
+            kzg.prove(&empty, FieldElement::one()),
+            Err(KzgError::EmptyPolynomial)
+        );
+
+        // x^2 doesn't fit in a degree 1 setup
+        let too_large = Polynomial::new(vec![
+            FieldElement::zero(),
+            FieldElement::zero(),
+            FieldElement::one(),
+        ]);
+        assert_eq!(
+            kzg.check_polynomial(&too_large),
+            Err(KzgError::DegreeTooLarge)
+        );
+        // Debug builds panic first, see test_commit_over_degree_panics_in_debug
+        #[cfg(not(debug_assertions))]
+        {
+            assert_eq!(kzg.commit(&too_large), Err(KzgError::DegreeTooLarge));
+            assert_eq!(
+                kzg.prove(&too_large, FieldElement::one()),
+                Err(KzgError::DegreeTooLarge)
+            );
+        }
+
+        let poly = Polynomial::new(vec![FieldElement::one(), FieldElement::new(2)]);
+        let commitment = kzg.commit(&poly).unwrap();
+        let z = FieldElement::new(3);
+        let (y, proof) = kzg.prove(&poly, z.clone()).unwrap();
+        let off_curve = Point {
+            x: FieldElement::new(1),
+            y: FieldElement::new(3),
+            is_infinity: false,
+        };
+        assert_eq!(
+            kzg.verify(&off_curve, z.clone(), y.clone(), &proof),
+            Err(KzgError::PointNotOnCurve)
+        );
+        assert_eq!(
+            kzg.verify(&commitment, z.clone(), y.clone(), &off_curve),
+            Err(KzgError::PointNotOnCurve)
+        );
+        assert_eq!(
+            kzg.verify(&commitment, z, y.add(&FieldElement::one()), &proof),
+            Err(KzgError::PairingMismatch)
+        );
+    }
+
+    #[test]
+    fn test_new_with_tau_is_deterministic() {
+        let kzg1 = KZG::new_with_tau(3, FieldElement::new(5));
+        let kzg2 = KZG::new_with_tau(3, FieldElement::new(5));
+        assert_eq!(kzg1.setup_g1, kzg2.setup_g1);
+        assert_eq!(kzg1.setup_g2, kzg2.setup_g2);
+        assert_eq!(
+            kzg1.setup_g1[1],
+            kzg1.curve
+                .generator_g1()
+                .scalar_mul(&kzg1.curve, Scalar::new(5))
+        );
+
+        let poly = Polynomial::new(vec![
+            FieldElement::new(4),
+            FieldElement::new(3),
+            FieldElement::new(5),
+            FieldElement::one(),
+        ]);
+        assert_eq!(kzg1.commit(&poly), kzg2.commit(&poly));
+
+        let seeded1 = KZG::new_seeded(3, 42);
+        let seeded2 = KZG::new_seeded(3, 42);
+        assert_eq!(seeded1.setup_g1, seeded2.setup_g1);
+        assert_eq!(seeded1.commit(&poly), seeded2.commit(&poly));
+    }
+
+    #[test]
+    fn test_combine_proofs() {
+        let kzg = KZG::new(3);
+        let p = Polynomial::new(vec![FieldElement::new(4), FieldElement::new(3)]); // 4 + 3x
+        let q = Polynomial::new(vec![
+            FieldElement::one(),
+            FieldElement::zero(),
+            FieldElement::new(5),
+            FieldElement::new(2),
+        ]); // 1 + 5x^2 + 2x^3
+        let z = FieldElement::new(6);
+        let (a, proof_p) = kzg.prove(&p, z.clone()).unwrap();
+        let (b, proof_q) = kzg.prove(&q, z.clone()).unwrap();
+
+        let (sum, proof) = kzg.combine_proofs(
+            &[(proof_p, a), (proof_q, b)],
+            &[FieldElement::one(), FieldElement::one()],
+        );
+        let commitment = kzg
+            .curve
+            .add(&kzg.commit(&p).unwrap(), &kzg.commit(&q).unwrap());
+        assert_eq!(kzg.verify(&commitment, z.clone(), sum, &proof), Ok(()));
+        // Same opening as proving p + q directly
+        assert_eq!(kzg.prove(&(p + q), z).unwrap().1, proof);
+    }
+
+    #[test]
+    fn test_new_with_rng_is_deterministic() {
+        let kzg1 = KZG::new_with_rng(3, &mut StdRng::seed_from_u64(7));
+        let kzg2 = KZG::new_with_rng(3, &mut StdRng::seed_from_u64(7));
+        assert_eq!(kzg1.setup_g1, kzg2.setup_g1);
+        assert_eq!(kzg1.setup_g2, kzg2.setup_g2);
+        // new_seeded is the same setup
+        assert_eq!(KZG::new_seeded(3, 7).setup_g1, kzg1.setup_g1);
+    }
+
+    #[test]
+    fn test_commit_lagrange_matches_commit() {
+        let mut kzg = KZG::new(3);
+        let domain = EvaluationDomain::new(4).unwrap();
+        kzg.setup_lagrange(&domain);
+
+        // 20 and 37 only agree with 3 mod 17
+        let evals: Vec<FieldElement> = [7, 20, 37, 9].into_iter().map(FieldElement::new).collect();
+        let poly = KZG::interpolate(&evals, &domain);
+        assert_eq!(
+            kzg.commit_scalar_polynomial(&poly),
+            Ok(kzg.commit_lagrange(&evals))
+        );
+        for (x, y) in domain.elements.iter().zip(&evals) {
+            assert_eq!(
+                poly.evaluate(&Scalar::from_field_element(x)),
+                Scalar::from_field_element(y)
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Domain points collide in the scalar field")]
+    fn test_setup_lagrange_domain_too_large() {
+        // 20 points can't be distinct in F_17
+        let mut kzg = KZG::new(19);
+        kzg.setup_lagrange(&EvaluationDomain::new(20).unwrap());
+    }
+
+    #[test]
+    fn test_commit_quotient_from_evals() {
+        let mut kzg = KZG::new(3);
+        let domain = EvaluationDomain::new(4).unwrap();
+        let evals: Vec<FieldElement> = [7, 0, 2, 9].into_iter().map(FieldElement::new).collect();
+        assert_eq!(kzg.domain(), &domain);
+        assert_eq!(
+            kzg.commit_quotient_from_evals(&evals, &domain),
+            kzg.commit_scalar_polynomial(&KZG::interpolate(&evals, &domain))
+        );
+        kzg.setup_lagrange(&domain);
+        assert_eq!(
+            kzg.commit_quotient_from_evals(&evals, &domain),
+            Ok(kzg.commit_lagrange(&evals))
+        );
+
+        // A bigger setup takes the smaller domain too
+        let kzg = KZG::new(4);
+        assert_eq!(kzg.domain().size, 5);
+        assert_eq!(
+            kzg.commit_quotient_from_evals(&evals, &domain),
+            kzg.commit_scalar_polynomial(&KZG::interpolate(&evals, &domain))
+        );
+
+        // Degree 2 only fits a domain of size 2
+        let kzg = KZG::new(2);
+        let evals = [FieldElement::new(3), FieldElement::new(8)];
+        let poly = KZG::interpolate(&evals, kzg.domain());
+        assert_eq!(
+            kzg.commit_quotient_from_evals(&evals, kzg.domain()),
+            kzg.commit_scalar_polynomial(&poly)
+        );
+    }
+
+    #[test]
+    fn test_commit_quotient_from_evals_errors() {
+        let kzg = KZG::new(3);
+        let domain = EvaluationDomain::new(4).unwrap();
+        assert_eq!(
+            kzg.commit_quotient_from_evals(&vec![FieldElement::one(); 5], &domain),
+            Err(KzgError::WrongEvalCount)
+        );
+
+        // 5 evaluations interpolate to degree 4, too large for the setup
+        let domain = EvaluationDomain::new(5).unwrap();
+        assert_eq!(
+            kzg.commit_quotient_from_evals(&vec![FieldElement::one(); 5], &domain),
+            Err(KzgError::DegreeTooLarge)
+        );
+    }
+
+    #[test]
+    fn test_extend() {
+        let tau = FieldElement::new(5);
+        let mut kzg = KZG::new_with_tau(2, tau.clone());
+        assert_eq!(kzg.extend(4, tau.clone()), Ok(()));
+        assert_eq!(kzg.setup_g1, KZG::new_with_tau(4, tau.clone()).setup_g1);
+
+        assert_eq!(kzg.extend(3, tau), Err(KzgError::DegreeTooSmall));
+        assert_eq!(kzg.extend(6, FieldElement::new(6)), Err(KzgError::WrongTau));
+        assert_eq!(kzg.setup_g1.len(), 5);
+    }
+
+    #[test]
+    fn test_verify_srs() {
+        let mut kzg = KZG::new(4);
+        assert!(kzg.verify_srs());
+
+        // [tau^2]G swapped for [tau^2 + 1]G
+        kzg.setup_g1[2] = kzg.curve.add(&kzg.setup_g1[2], &kzg.curve.generator_g1());
+        assert!(!kzg.verify_srs());
+    }
+
+    #[test]
+    fn test_kzg_constant_polynomial() {
+        let kzg = KZG::new(2);
+        let seven = Polynomial::new(vec![FieldElement::new(7)]);
+        let commitment = kzg.commit(&seven).unwrap();
+        assert_eq!(
+            commitment,
+            kzg.curve
+                .generator_g1()
+                .scalar_mul(&kzg.curve, Scalar::new(7))
+        );
+
+        let z = FieldElement::new(5);
+        let (y, proof) = kzg.prove(&seven, z.clone()).unwrap();
+        assert_eq!(y, FieldElement::new(7));
+        assert!(proof.is_infinity); // the quotient is zero
+        assert_eq!(kzg.verify(&commitment, z.clone(), y, &proof), Ok(()));
+        assert_eq!(
+            kzg.verify(&commitment, z, FieldElement::new(8), &proof),
+            Err(KzgError::PairingMismatch)
+        );
+    }
+
+    #[test]
+    fn test_kzg_zero_polynomial() {
+        let kzg = KZG::new(2);
+        let zero = Polynomial::new(vec![FieldElement::zero()]);
+        let commitment = kzg.commit(&zero).unwrap();
+        assert!(commitment.is_infinity);
+        let z = FieldElement::new(3);
+        let (y, proof) = kzg.prove(&zero, z.clone()).unwrap();
+        assert_eq!(y, FieldElement::zero());
+        assert!(proof.is_infinity);
+        assert_eq!(kzg.verify(&commitment, z, y, &proof), Ok(()));
+    }
+
+    #[test]
+    fn test_compute_witness_polynomial() {
+        let kzg = KZG::new(3);
+        // 2 + 3x + x^2 at z = 2
+        let poly = Polynomial::new(vec![
+            FieldElement::new(2),
+            FieldElement::new(3),
+            FieldElement::one(),
+        ]);
+        let z = FieldElement::new(2);
+        let (y, witness) = kzg.compute_witness_polynomial(&poly, z.clone());
+        assert_eq!(y, FieldElement::new(12));
+        let reconstructed = |witness: ScalarPolynomial, z: &FieldElement, y: &FieldElement| {
+            let z = Scalar::from_field_element(z);
+            let x_minus_z = ScalarPolynomial::new(vec![z.negate(), Scalar::one()]);
+            witness * x_minus_z + ScalarPolynomial::new(vec![Scalar::from_field_element(y)])
+        };
+        assert_eq!(
+            reconstructed(witness.clone(), &z, &y),
+            ScalarPolynomial::from(&poly)
+        );
+
+        let (proof_y, proof) = kzg.prove(&poly, z).unwrap();
+        assert_eq!(proof_y, y);
+        assert_eq!(Ok(proof), kzg.commit_scalar_polynomial(&witness));
+
+        // 20 + x with z = 30: both wrap mod 17, p reads as 3 + x and z as 13
+        let poly = Polynomial::new(vec![FieldElement::new(20), FieldElement::one()]);
+        let z = FieldElement::new(30);
+        let (y, witness) = kzg.compute_witness_polynomial(&poly, z.clone());
+        assert_eq!(y, FieldElement::new(16));
+        assert_eq!(witness, ScalarPolynomial::new(vec![Scalar::one()]));
+        assert_eq!(
+            reconstructed(witness.clone(), &z, &y),
+            ScalarPolynomial::from(&poly)
+        );
+        let (proof_y, proof) = kzg.prove(&poly, z).unwrap();
+        assert_eq!(proof_y, y);
+        assert_eq!(Ok(proof), kzg.commit_scalar_polynomial(&witness));
+
+        let (y, witness) =
+            kzg.compute_witness_polynomial(&Polynomial::zero(), FieldElement::new(5));
+        assert_eq!(y, FieldElement::zero());
+        assert_eq!(witness.degree(), None);
+    }
+
+    #[test]
+    fn test_kzg_domain_cache() {
+        let mut kzg = KZG::new_with_tau(3, FieldElement::new(5));
+        let domain = kzg.domain();
+        assert_eq!(domain.size, 4);
+        assert!(std::ptr::eq(domain, kzg.domain()));
+        assert_eq!(domain.elements, kzg.domain().elements);
+
+        // 3 powers of tau only fit a domain of size 2, the divisor of 100 below
+        assert_eq!(KZG::new_with_tau(2, FieldElement::new(5)).domain().size, 2);
+
+        kzg.extend(4, FieldElement::new(5)).unwrap();
+        assert_eq!(kzg.domain().size, 5);
+        kzg.extend(23, FieldElement::new(5)).unwrap();
+        assert_eq!(kzg.domain().size, 20);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "Polynomial of degree 2 doesn't fit in an SRS of degree 1")]
+    fn test_commit_over_degree_panics_in_debug() {
+        let kzg = KZG::new(1);
+        let x_2 = Polynomial::new(vec![
+            FieldElement::zero(),
+            FieldElement::zero(),
+            FieldElement::one(),
+        ]);
+        let _ = kzg.commit(&x_2);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "p(x) - y isn't divisible by (x - z)")]
+    fn test_prove_with_wrong_evaluation_panics_in_debug() {
+        let kzg = KZG::new(2);
+        // p(x) = 1 + x + x^2, p(2) = 7 but opened claiming 8
+        let coeffs = vec![Scalar::one(); 3];
+        let z = Scalar::new(2);
+        assert_eq!(KZG::evaluate_scalars(&coeffs, &z), Scalar::new(7));
+        kzg.open_scalars(coeffs, &z, &Scalar::new(8));
+    }
+
+    #[test]
+    fn test_kzg_is_send_and_sync() {
+        fn assert_shareable<T: Send + Sync + std::panic::RefUnwindSafe>() {}
+        assert_shareable::<KZG>();
     }
 }
@@ -0,0 +1,84 @@
+use super::elliptic_curve::{EllipticCurve, Point};
+use super::field::{FieldElement, Scalar};
+
+/*
+    Pedersen vector commitment: C = ∑ [v_i]G_i + [r]H
+
+    The generators come from hash_to_curve, so nobody knows the discrete
+    log relations between them. Unlike KZG the commitment is hiding thanks
+    to the blinding factor r. Values are read as scalars (mod 17), and in a
+    group of order 17 this is of course only a toy.
+*/
+
+#[derive(Clone, Debug)]
+pub struct PedersenCommit {
+    curve: EllipticCurve,
+    generators: Vec<Point>,    // G_0, ..., G_(n-1)
+    blinding_generator: Point, // H
+}
+
+impl PedersenCommit {
+    /// Derives n value generators and the blinding generator
+    #[allow(dead_code)]
+    pub fn new(size: usize) -> Self {
+        let curve = EllipticCurve::new();
+        let generators = (0..size)
+            .map(|i| curve.hash_to_curve(format!("pedersen generator {i}").as_bytes()))
+            .collect();
+        let blinding_generator = curve.hash_to_curve(b"pedersen blinding generator");
+        PedersenCommit {
+            curve,
+            generators,
+            blinding_generator,
+        }
+    }
+
+    /// ∑ [v_i]G_i + [blinding]H
+    #[allow(dead_code)]
+    pub fn commit(&self, values: &[FieldElement], blinding: FieldElement) -> Point {
+        assert!(
+            values.len() <= self.generators.len(),
+            "Too many values for the number of generators"
+        );
+        let mut commitment = self
+            .blinding_generator
+            .scalar_mul(&self.curve, Scalar::from_field_element(&blinding));
+        for (value, generator) in values.iter().zip(&self.generators) {
+            let term = generator.scalar_mul(&self.curve, Scalar::from_field_element(value));
+            self.curve.add_assign(&mut commitment, &term);
+        }
+        commitment
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn elements(values: &[u64]) -> Vec<FieldElement> {
+        values.iter().map(|v| FieldElement::new(*v)).collect()
+    }
+
+    #[test]
+    fn test_pedersen_is_homomorphic() {
+        let pedersen = PedersenCommit::new(3);
+        let a = pedersen.commit(&elements(&[1, 2, 3]), FieldElement::new(4));
+        let b = pedersen.commit(&elements(&[5, 0, 7]), FieldElement::new(2));
+        let sum = pedersen.commit(&elements(&[6, 2, 10]), FieldElement::new(6));
+        assert_eq!(pedersen.curve.add(&a, &b), sum);
+    }
+
+    #[test]
+    fn test_pedersen_binds_values() {
+        let pedersen = PedersenCommit::new(3);
+        let blinding = FieldElement::new(9);
+        assert_ne!(
+            pedersen.commit(&elements(&[1, 2, 3]), blinding.clone()),
+            pedersen.commit(&elements(&[3, 2, 1]), blinding.clone())
+        );
+        assert_ne!(
+            pedersen.commit(&elements(&[1, 2, 3]), blinding.clone()),
+            pedersen.commit(&elements(&[1, 2, 4]), blinding)
+        );
+    }
+}
@@ -1,6 +1,9 @@
-use super::field::FieldElement;
+use super::domain::EvaluationDomain;
+use super::field::{FieldElement, FieldElementExt, Scalar};
+use crate::generic_polynomial::Polynomial as GenericPolynomial;
 
-use std::ops::{Add, Mul, Sub};
+use std::iter::{Product, Sum};
+use std::ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign};
 
 /// Note: A polynomial built with the following coefficients:
 ///     [1, 2, 3, 4] will represent y = 1 + 2x + 3x^2 + 4x^3
@@ -13,6 +16,19 @@ pub struct Polynomial {
     pub coeffs: Vec<FieldElement>,
 }
 
+/// poly![1, 2, 3] is Polynomial::new(vec![FieldElement::new(1), ...]),
+/// coefficients from the lowest degree as in `new`
+#[allow(unused_macros)]
+macro_rules! poly {
+    ($($coeff:expr),* $(,)?) => {
+        $crate::my_plonk::polynomial::Polynomial::new(vec![
+            $($crate::my_plonk::field::FieldElement::new($coeff)),*
+        ])
+    };
+}
+#[allow(unused_imports)]
+pub(crate) use poly;
+
 impl Polynomial {
     pub fn new(coeffs: Vec<FieldElement>) -> Self {
         let mut p: Polynomial = Polynomial {
@@ -25,6 +41,92 @@ impl Polynomial {
         p
     }
 
+    /// The zero polynomial
+    #[allow(dead_code)]
+    pub fn zero() -> Self {
+        Polynomial::new(vec![])
+    }
+
+    /// The constant polynomial 1
+    #[allow(dead_code)]
+    pub fn one() -> Self {
+        Polynomial::new(vec![FieldElement::one()])
+    }
+
+    /// True for both representations of zero: [] and [0]
+    #[allow(dead_code)]
+    pub fn is_zero(&self) -> bool {
+        self.coeffs.iter().all(|c| c == &FieldElement::zero())
+    }
+
+    #[allow(dead_code)]
+    pub fn is_one(&self) -> bool {
+        self.coeffs.first() == Some(&FieldElement::one())
+            && self.coeffs[1..].iter().all(|c| c == &FieldElement::zero())
+    }
+
+    /// Appends c as the coefficient of the next-highest degree. Zeros are kept
+    /// (not trimmed) so that the following push lands on the right degree.
+    /// Start from `Polynomial::zero()`, which has no coefficients.
+    #[allow(dead_code)]
+    pub fn push_coeff(&mut self, c: FieldElement) {
+        self.coeffs.push(c);
+    }
+
+    /// Sets the coefficient of x^degree, zero-extending if needed
+    #[allow(dead_code)]
+    pub fn set_coeff(&mut self, degree: usize, c: FieldElement) {
+        if degree >= self.coeffs.len() {
+            self.coeffs.resize(degree + 1, FieldElement::zero());
+        }
+        self.coeffs[degree] = c;
+        self.trim();
+    }
+
+    /// Coefficient of x^degree, zero beyond the stored coefficients
+    #[allow(dead_code)]
+    pub fn coeff(&self, degree: usize) -> FieldElement {
+        self.coeffs
+            .get(degree)
+            .cloned()
+            .unwrap_or_else(FieldElement::zero)
+    }
+
+    /// (degree, coeff) pairs from the constant term up
+    #[allow(dead_code)]
+    pub fn iter_terms(&self) -> impl Iterator<Item = (usize, &FieldElement)> {
+        self.coeffs.iter().enumerate()
+    }
+
+    /// Same as `iter_terms`, skipping the zero coefficients
+    #[allow(dead_code)]
+    pub fn iter_nonzero_terms(&self) -> impl Iterator<Item = (usize, &FieldElement)> {
+        self.iter_terms()
+            .filter(|(_, c)| *c != &FieldElement::zero())
+    }
+
+    /// Highest nonzero coefficient, zero for the zero polynomial
+    #[allow(dead_code)]
+    pub fn leading_coefficient(&self) -> FieldElement {
+        self.coeffs
+            .iter()
+            .rev()
+            .find(|c| *c != &FieldElement::zero())
+            .cloned()
+            .unwrap_or_else(FieldElement::zero)
+    }
+
+    /// Two points sharing an x make (xi - xj) zero, and `inverse()` would
+    /// silently return zero instead of failing, giving a wrong polynomial
+    fn assert_distinct_xs(points: &[(FieldElement, FieldElement)]) {
+        for (i, (xi, _)) in points.iter().enumerate() {
+            assert!(
+                !points[..i].iter().any(|(xj, _)| xj == xi),
+                "Interpolation points must have distinct x-coordinates"
+            );
+        }
+    }
+
     fn trim(&mut self) {
         while self.coeffs.len() > 1 && self.coeffs.last().unwrap() == &FieldElement::zero() {
             self.coeffs.pop();
@@ -37,72 +139,203 @@ impl Polynomial {
             .iter()
             .rev()
             .fold(FieldElement::zero(), |acc, coeff| {
-                acc.multiply(&x).add(&coeff)
+                acc.multiply(&x).add(coeff)
             })
     }
 
+    /// Horner evaluation at a point of F_101^2, lifting each coefficient
+    /// into the extension of x (imaginary part zero)
     #[allow(dead_code)]
-    fn scalar_mul(&self, scalar: FieldElement) -> Self {
-        Polynomial {
-            coeffs: self.coeffs.iter().map(|c| c.multiply(&scalar)).collect(),
-        }
+    pub fn evaluate_ext(&self, x: &FieldElementExt) -> FieldElementExt {
+        self.coeffs.iter().rev().fold(
+            x.with_parts(FieldElement::zero(), FieldElement::zero()),
+            |acc, coeff| {
+                acc.multiply(x)
+                    .add(&x.with_parts(coeff.clone(), FieldElement::zero()))
+            },
+        )
     }
 
     #[allow(dead_code)]
-    pub fn divide(&self, divisor: &Self) -> (Self, Self) {
+    pub fn scalar_mul(&self, scalar: FieldElement) -> Self {
+        Polynomial::new(self.coeffs.iter().map(|c| c.multiply(&scalar)).collect())
+    }
+
+    /// Multiplies every coefficient by the inverse of the scalar.
+    /// Panics on a zero scalar, as `inverse()` would silently return zero.
+    #[allow(dead_code)]
+    pub fn scalar_div(&self, scalar: FieldElement) -> Self {
         assert!(
-            !divisor.coeffs.is_empty(),
-            "Cannot divide by zero polynomial"
+            scalar != FieldElement::zero(),
+            "Cannot divide polynomial by zero scalar"
         );
+        self.scalar_mul(scalar.inverse())
+    }
 
-        // If divisor is a constant (degree 0), handle scalar division
-        if divisor.coeffs.len() == 1 {
-            let scalar: &FieldElement = &divisor.coeffs[0];
-            let inv_scalar: FieldElement = scalar.inverse();
-            let quotient_coeffs: Vec<FieldElement> = self
-                .coeffs
-                .iter()
-                .map(|c| c.multiply(&inv_scalar))
-                .collect();
-            return (
-                Polynomial::new(quotient_coeffs),
-                Polynomial::new(vec![]), // Remainder is 0
-            );
+    /// Newton form P(x) = c_0 + c_1(x - x_0) + c_2(x - x_0)(x - x_1) + ...
+    /// where c_i = f[x_0, ..., x_i] are the divided differences.
+    /// Adding a point only appends one more term, unlike Lagrange.
+    #[allow(dead_code)]
+    pub fn newton_interpolate(points: &[(FieldElement, FieldElement)]) -> Self {
+        assert!(!points.is_empty(), "Need at least one point");
+        Self::assert_distinct_xs(points);
+        let n = points.len();
+        let mut divided: Vec<FieldElement> = points.iter().map(|(_, y)| y.clone()).collect();
+        for j in 1..n {
+            for i in (j..n).rev() {
+                let num = divided[i].substract(&divided[i - 1]);
+                let den = points[i].0.substract(&points[i - j].0);
+                divided[i] = num.divide(&den);
+            }
         }
 
-        let mut dividend: Vec<FieldElement> = self.coeffs.clone();
-        let divisor_deg: usize = divisor.coeffs.len() - 1;
-        let divisor_lead: &FieldElement = divisor.coeffs.last().unwrap(); // Leading coefficient
-        let inv_divisor_lead: FieldElement = divisor_lead.inverse();
-
-        // If dividend degree < divisor degree, quotient is 0, remainder is dividend
-        if dividend.len() <= divisor_deg {
-            return (Polynomial::new(vec![]), Polynomial::new(dividend));
+        // Horner over the Newton basis, from the highest term down
+        let mut result = Polynomial::new(vec![divided[n - 1].clone()]);
+        for k in (0..n - 1).rev() {
+            result *= Polynomial::new(vec![points[k].0.negate(), FieldElement::one()]);
+            result += Polynomial::new(vec![divided[k].clone()]);
         }
+        result
+    }
 
-        let mut quotient: Vec<FieldElement> =
-            vec![FieldElement::zero(); dividend.len() - divisor_deg];
-        for i in (0..quotient.len()).rev() {
-            let dividend_deg = i + divisor_deg;
-            let term = dividend[dividend_deg].clone().multiply(&inv_divisor_lead);
-            quotient[i] = term.clone();
+    /// Polynomial taking values[i] at ω^i over the domain. Built with Lagrange
+    /// interpolation for now, the signature allows swapping in an IFFT later.
+    #[allow(dead_code)]
+    pub fn from_domain_values(values: &[FieldElement], domain: &EvaluationDomain) -> Self {
+        assert_eq!(
+            values.len(),
+            domain.size,
+            "Need exactly one value per domain element"
+        );
+        let points: Vec<(FieldElement, FieldElement)> = domain
+            .elements
+            .iter()
+            .cloned()
+            .zip(values.iter().cloned())
+            .collect();
+        Polynomial::lagrange_interpolate(&points)
+    }
 
-            // Subtract term * divisor from dividend
-            for j in 0..divisor.coeffs.len() {
-                let prod: FieldElement = divisor.coeffs[j].clone().multiply(&term);
-                dividend[dividend_deg - (divisor_deg - j)] = dividend
-                    [dividend_deg - (divisor_deg - j)]
-                    .clone()
-                    .substract(&prod);
-            }
+    /// Evaluation form over the domain: [p(ω^0), ..., p(ω^(n-1))].
+    /// Only invertible for degree < n, higher terms wrap around mod x^n - 1.
+    #[allow(dead_code)]
+    pub fn to_evaluations(&self, domain: &EvaluationDomain) -> Vec<FieldElement> {
+        domain
+            .elements
+            .iter()
+            .map(|x| self.evaluate(x.clone()))
+            .collect()
+    }
+
+    /// Coefficient form from the evaluations over the domain, the inverse of
+    /// `to_evaluations`. Same as `from_domain_values`, named to pair with it.
+    #[allow(dead_code)]
+    pub fn from_evaluations(evals: &[FieldElement], domain: &EvaluationDomain) -> Self {
+        Self::from_domain_values(evals, domain)
+    }
+
+    /// Returns p(ω·x) by scaling each coefficient c_i by ω^i. PLONK's
+    /// permutation argument uses this to get z(ωx) from z(x).
+    #[allow(dead_code)]
+    pub fn shift_domain(&self, omega: FieldElement) -> Self {
+        let mut omega_pow = FieldElement::one();
+        let mut coeffs = Vec::with_capacity(self.coeffs.len());
+        for c in &self.coeffs {
+            coeffs.push(c.multiply(&omega_pow));
+            omega_pow = omega_pow.multiply(&omega);
         }
+        Polynomial::new(coeffs)
+    }
 
-        // Trim leading zeros from remainder
-        while dividend.len() > divisor_deg && dividend.last().unwrap() == &FieldElement::zero() {
-            dividend.pop();
+    /// Returns the leading coefficient together with the polynomial scaled
+    /// so that its leading coefficient is one. The zero polynomial is
+    /// returned unchanged with a leading coefficient of zero.
+    #[allow(dead_code)]
+    pub fn make_monic(&self) -> (FieldElement, Self) {
+        let lead = self.leading_coefficient();
+        if lead == FieldElement::zero() {
+            return (lead, self.clone());
         }
+        (lead.clone(), self.scalar_div(lead))
+    }
+
+    /// Long division, returns (quotient, remainder)
+    #[allow(dead_code)]
+    pub fn divide(&self, divisor: &Self) -> (Self, Self) {
+        let (quotient, remainder) =
+            GenericPolynomial::from(self.clone()).divide(&divisor.clone().into());
+        (quotient.into(), remainder.into())
+    }
 
-        (Polynomial::new(quotient), Polynomial::new(dividend))
+    /// Keeps only the terms of degree <= degree
+    #[allow(dead_code)]
+    pub fn truncate(&self, degree: usize) -> Self {
+        Polynomial::new(self.coeffs.iter().take(degree + 1).cloned().collect())
+    }
+
+    /// (low, high) with low = terms of degree <= degree and
+    /// self = low + x^(degree + 1)·high
+    #[allow(dead_code)]
+    pub fn split_at(&self, degree: usize) -> (Self, Self) {
+        let high = Polynomial::new(self.coeffs.iter().skip(degree + 1).cloned().collect());
+        (self.truncate(degree), high)
+    }
+
+    /// self·x^k by prepending k zero coefficients, cheaper than building x^k and multiplying
+    #[allow(dead_code)]
+    pub fn mul_by_x_pow(&self, k: usize) -> Self {
+        let mut coeffs = vec![FieldElement::zero(); k];
+        coeffs.extend(self.coeffs.iter().cloned());
+        Polynomial::new(coeffs)
+    }
+
+    /// Slices the coefficients into n chunks of chunk_degree coefficients each,
+    /// so that self = ∑ x^(i·chunk_degree)·t_i (PLONK's t_lo, t_mid, t_hi).
+    /// Trailing chunks are zero if self is short.
+    #[allow(dead_code)]
+    pub fn split_into(&self, n: usize, chunk_degree: usize) -> Vec<Self> {
+        assert!(
+            self.degree() < n * chunk_degree,
+            "Polynomial doesn't fit in {} chunks of {} coefficients",
+            n,
+            chunk_degree
+        );
+        (0..n)
+            .map(|i| {
+                let chunk = self
+                    .coeffs
+                    .iter()
+                    .skip(i * chunk_degree)
+                    .take(chunk_degree)
+                    .cloned()
+                    .collect();
+                Polynomial::new(chunk)
+            })
+            .collect()
+    }
+
+    /// The quotient when divisor divides self exactly, None if there's a remainder
+    #[allow(dead_code)]
+    pub fn div_exact(&self, divisor: &Self) -> Option<Self> {
+        let (quotient, remainder) = self.divide(divisor);
+        remainder.is_zero().then_some(quotient)
+    }
+
+    /// The c with self = c·other, None if there's no such c. Zero is 0·other
+    /// for any other, while no multiple of zero is a nonzero polynomial.
+    #[allow(dead_code)]
+    pub fn is_scalar_multiple_of(&self, other: &Self) -> Option<FieldElement> {
+        if self.is_zero() {
+            return Some(FieldElement::zero());
+        }
+        if other.is_zero() {
+            return None;
+        }
+        // Leading coefficients fix c, the rest only has to agree
+        let c = self
+            .leading_coefficient()
+            .divide(&other.leading_coefficient());
+        (other.scalar_mul(c.clone()) == *self).then_some(c)
     }
 
     #[allow(dead_code)]
@@ -124,6 +357,24 @@ impl Polynomial {
         Polynomial::new(coeffs)
     }
 
+    /// Remainder modulo x^n - 1. Since x^n ≡ 1, the coefficient of x^i just
+    /// folds onto x^(i mod n), no long division needed.
+    #[allow(dead_code)]
+    pub fn rem_vanishing(&self, n: usize) -> Self {
+        assert!(n > 0, "Vanishing polynomial needs n > 0");
+        let mut coeffs = vec![FieldElement::zero(); n.min(self.coeffs.len())];
+        for (i, c) in self.coeffs.iter().enumerate() {
+            coeffs[i % n] += c.clone();
+        }
+        Polynomial::new(coeffs)
+    }
+
+    /// Whether both polynomials agree modulo x^n - 1
+    #[allow(dead_code)]
+    pub fn equal_mod_vanishing(&self, other: &Polynomial, n: usize) -> bool {
+        self.rem_vanishing(n) == other.rem_vanishing(n)
+    }
+
     /// Resource I recommend to understand Lagrange Interpolation:
     /// LambdaClass YT video: https://www.youtube.com/watch?v=REnFOKo9gXs
     ///
@@ -131,30 +382,171 @@ impl Polynomial {
     /// where l_i(x)= ∏_j≠i (x−xj)/(xi−xj)
     #[allow(dead_code)]
     pub fn lagrange_interpolate(points: &[(FieldElement, FieldElement)]) -> Self {
-        assert!(!points.is_empty(), "Need at least one point");
-        let mut result = Polynomial::new(vec![]);
-
-        for (i, &(ref xi, ref yi)) in points.iter().enumerate() {
-            let mut term = Polynomial::new(vec![FieldElement::one()]); // Start with 1
-            let mut denominator = FieldElement::one();
-
-            for (j, &(ref xj, _)) in points.iter().enumerate() {
-                if i != j {
-                    // Numerator: (x - xj)
-                    let num = Polynomial::new(vec![xj.clone().negate(), FieldElement::one()]);
-                    term = term * num;
-                    // Denominator: (xi - xj)
-                    denominator = denominator.multiply(&xi.substract(xj));
+        Self::assert_distinct_xs(points);
+        GenericPolynomial::lagrange_interpolate(points).into()
+    }
+}
+
+/// Division and interpolation are shared with the other backend through
+/// `generic_polynomial`, the conversions only move the coefficients
+impl From<Polynomial> for GenericPolynomial<FieldElement> {
+    fn from(poly: Polynomial) -> Self {
+        GenericPolynomial::new(poly.coeffs)
+    }
+}
+
+impl From<GenericPolynomial<FieldElement>> for Polynomial {
+    fn from(poly: GenericPolynomial<FieldElement>) -> Self {
+        Polynomial::new(poly.coeffs)
+    }
+}
+
+/// Polynomial over the scalar field F_17, the field KZG commitments are
+/// homomorphic over
+pub type ScalarPolynomial = GenericPolynomial<Scalar>;
+
+/// Reads the coefficients as scalars, i.e. reduces them mod 17
+impl From<&Polynomial> for ScalarPolynomial {
+    fn from(poly: &Polynomial) -> Self {
+        GenericPolynomial::new(poly.coeffs.iter().map(Scalar::from_field_element).collect())
+    }
+}
+
+/// Lagrange interpolation over a fixed set of x-coordinates. With
+/// l(x) = ∏_j (x - x_j) and the barycentric weights w_i = 1 / ∏_j≠i (x_i - x_j),
+/// the basis polynomials are L_i(x) = w_i·l(x) / (x - x_i) and
+///     P(x) = ∑_i y_i·L_i(x)
+/// The L_i only depend on the xs, so they're computed once and each
+/// interpolation is just a linear combination of them.
+#[derive(Debug, Clone)]
+pub struct LagrangePrecompute {
+    pub xs: Vec<FieldElement>,
+    pub basis: Vec<Polynomial>, // L_i, one per x-coordinate
+}
+
+impl LagrangePrecompute {
+    #[allow(dead_code)]
+    pub fn new(xs: &[FieldElement]) -> Self {
+        assert!(!xs.is_empty(), "Need at least one point");
+        let mut vanishing = Polynomial::one();
+        for x in xs {
+            vanishing *= Polynomial::new(vec![x.negate(), FieldElement::one()]);
+        }
+        let basis = xs
+            .iter()
+            .enumerate()
+            .map(|(i, xi)| {
+                let mut denominator = FieldElement::one();
+                for (j, xj) in xs.iter().enumerate() {
+                    if i != j {
+                        denominator = denominator.multiply(&xi.substract(xj));
+                    }
                 }
+                assert!(
+                    denominator != FieldElement::zero(),
+                    "Interpolation points must have distinct x-coordinates"
+                );
+                let linear = Polynomial::new(vec![xi.negate(), FieldElement::one()]);
+                let (numerator, _) = vanishing.divide(&linear);
+                numerator.scalar_mul(denominator.inverse())
+            })
+            .collect();
+        LagrangePrecompute {
+            xs: xs.to_vec(),
+            basis,
+        }
+    }
+
+    /// The polynomial of degree < n taking ys[i] at xs[i]
+    #[allow(dead_code)]
+    pub fn interpolate(&self, ys: &[FieldElement]) -> Polynomial {
+        assert_eq!(ys.len(), self.xs.len(), "Expected one y per x-coordinate");
+        let mut result = Polynomial::zero();
+        for (basis, yi) in self.basis.iter().zip(ys) {
+            if yi == &FieldElement::zero() {
+                continue;
             }
+            result += basis.scalar_mul(yi.clone());
+        }
+        result
+    }
+}
 
-            let inv_denominator = denominator.inverse();
-            term = term.scalar_mul(inv_denominator);
-            term = term.scalar_mul(yi.clone());
-            result = result + term;
+/// Sparse representation as (degree, coefficient) pairs, for polynomials like
+/// x^n - 1 whose dense coefficient vector would be almost entirely zeros.
+#[derive(Debug, Clone)]
+pub struct SparsePolynomial {
+    pub terms: Vec<(usize, FieldElement)>,
+}
+
+impl SparsePolynomial {
+    #[allow(dead_code)]
+    pub fn new(terms: Vec<(usize, FieldElement)>) -> Self {
+        SparsePolynomial {
+            terms: terms
+                .into_iter()
+                .filter(|(_, c)| c != &FieldElement::zero())
+                .collect(),
         }
+    }
 
-        result
+    /// x^n - 1 stored as {(0, -1), (n, 1)}
+    #[allow(dead_code)]
+    pub fn vanishing_polynomial(n: usize) -> Self {
+        SparsePolynomial::new(vec![
+            (0, FieldElement::one().negate()),
+            (n, FieldElement::one()),
+        ])
+    }
+
+    #[allow(dead_code)]
+    pub fn evaluate(&self, x: FieldElement) -> FieldElement {
+        self.terms
+            .iter()
+            .fold(FieldElement::zero(), |acc, (degree, coeff)| {
+                acc.add(&coeff.multiply(&x.pow(*degree as u64)))
+            })
+    }
+
+    #[allow(dead_code)]
+    pub fn to_dense(&self) -> Polynomial {
+        let len = self
+            .terms
+            .iter()
+            .map(|(degree, _)| degree + 1)
+            .max()
+            .unwrap_or(0);
+        let mut coeffs = vec![FieldElement::zero(); len];
+        for (degree, coeff) in &self.terms {
+            coeffs[*degree] += coeff.clone();
+        }
+        Polynomial::new(coeffs)
+    }
+
+    /// Product with a dense polynomial, touching only the nonzero terms
+    #[allow(dead_code)]
+    pub fn mul(&self, other: &Polynomial) -> Polynomial {
+        let max_degree = self.terms.iter().map(|(degree, _)| *degree).max();
+        let Some(max_degree) = max_degree.filter(|_| !other.coeffs.is_empty()) else {
+            return Polynomial::zero();
+        };
+        let mut result = vec![FieldElement::zero(); max_degree + other.coeffs.len()];
+        for (degree, coeff) in &self.terms {
+            for (j, b) in other.coeffs.iter().enumerate() {
+                result[degree + j] += coeff.multiply(b);
+            }
+        }
+        Polynomial::new(result)
+    }
+}
+
+// Compares the polynomials, not their representation: [] and [0] are equal
+impl PartialEq for Polynomial {
+    fn eq(&self, other: &Polynomial) -> bool {
+        let zero = FieldElement::zero();
+        let len = self.coeffs.len().max(other.coeffs.len());
+        (0..len)
+            .all(|i| self.coeffs.get(i).unwrap_or(&zero) == other.coeffs.get(i).unwrap_or(&zero))
     }
 }
 
@@ -189,6 +581,10 @@ impl Mul for Polynomial {
     fn mul(self, other: Self) -> Self {
         let n = self.coeffs.len();
         let m = other.coeffs.len();
+        // n + m - 1 would underflow when both operands are empty
+        if n == 0 || m == 0 {
+            return Polynomial::zero();
+        }
         let mut result: Vec<FieldElement> = vec![FieldElement::zero(); n + m - 1];
 
         for i in 0..n {
@@ -204,6 +600,7 @@ impl Mul for Polynomial {
 
 impl Sub for Polynomial {
     type Output = Self;
+    #[allow(clippy::suspicious_arithmetic_impl)]
     fn sub(self, other: Self) -> Self {
         let negated: Polynomial = Polynomial {
             coeffs: other.coeffs.into_iter().map(|c| c.negate()).collect(),
@@ -212,6 +609,66 @@ impl Sub for Polynomial {
     }
 }
 
+impl AddAssign for Polynomial {
+    fn add_assign(&mut self, other: Self) {
+        if self.coeffs.len() < other.coeffs.len() {
+            self.coeffs.resize(other.coeffs.len(), FieldElement::zero());
+        }
+        for (a, b) in self.coeffs.iter_mut().zip(other.coeffs.iter()) {
+            *a += b.clone();
+        }
+        self.trim();
+    }
+}
+
+impl SubAssign for Polynomial {
+    fn sub_assign(&mut self, other: Self) {
+        if self.coeffs.len() < other.coeffs.len() {
+            self.coeffs.resize(other.coeffs.len(), FieldElement::zero());
+        }
+        for (a, b) in self.coeffs.iter_mut().zip(other.coeffs.iter()) {
+            *a = a.substract(b);
+        }
+        self.trim();
+    }
+}
+
+impl MulAssign for Polynomial {
+    fn mul_assign(&mut self, other: Self) {
+        if self.coeffs.is_empty() || other.coeffs.is_empty() {
+            self.coeffs.clear();
+            return;
+        }
+        let mut result: Vec<FieldElement> =
+            vec![FieldElement::zero(); self.coeffs.len() + other.coeffs.len() - 1];
+        for (i, a) in self.coeffs.iter().enumerate() {
+            for (j, b) in other.coeffs.iter().enumerate() {
+                result[i + j] += a.multiply(b);
+            }
+        }
+        self.coeffs = result;
+        self.trim();
+    }
+}
+
+impl Sum for Polynomial {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Polynomial::zero(), |mut acc, p| {
+            acc += p;
+            acc
+        })
+    }
+}
+
+impl Product for Polynomial {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Polynomial::one(), |mut acc, p| {
+            acc *= p;
+            acc
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -266,6 +723,34 @@ mod tests {
         assert_eq!(sum.coeffs, vec![FieldElement::new(4), FieldElement::new(6)]);
     }
 
+    #[test]
+    fn test_assign_ops() {
+        // P(x) = 1 + 2x + 3x^2, Q(x) = 5 + 4x
+        let p = Polynomial::new(vec![
+            FieldElement::new(1),
+            FieldElement::new(2),
+            FieldElement::new(3),
+        ]);
+        let q = Polynomial::new(vec![FieldElement::new(5), FieldElement::new(4)]);
+
+        let mut sum = q.clone();
+        sum += p.clone();
+        assert_eq!(sum.coeffs, (q.clone() + p.clone()).coeffs);
+
+        let mut difference = p.clone();
+        difference -= q.clone();
+        assert_eq!(difference.coeffs, (p.clone() - q.clone()).coeffs);
+
+        let mut product = p.clone();
+        product *= q.clone();
+        assert_eq!(product.coeffs, (p.clone() * q.clone()).coeffs);
+
+        // Leading terms cancelling out are trimmed
+        let mut cancelled = p.clone();
+        cancelled -= p;
+        assert_eq!(cancelled.coeffs, vec![FieldElement::zero()]);
+    }
+
     #[test]
     fn test_polynomial_divide() {
         // P(x) = 1 + 2x + 3x^2
@@ -313,6 +798,414 @@ mod tests {
         assert_eq!(z.evaluate(FieldElement::new(100)).value, 0);
     }
 
+    #[test]
+    fn test_scalar_div() {
+        let p = Polynomial::new(vec![
+            FieldElement::new(1),
+            FieldElement::new(2),
+            FieldElement::new(3),
+        ]);
+        let scalar = FieldElement::new(7);
+        let result = p.scalar_mul(scalar.clone()).scalar_div(scalar);
+        assert_eq!(result.coeffs, p.coeffs);
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot divide polynomial by zero scalar")]
+    fn test_scalar_div_by_zero() {
+        let p = Polynomial::new(vec![FieldElement::new(1), FieldElement::new(2)]);
+        p.scalar_div(FieldElement::zero());
+    }
+
+    #[test]
+    fn test_newton_interpolate_matches_lagrange() {
+        let points = vec![
+            (FieldElement::new(1), FieldElement::new(6)),
+            (FieldElement::new(2), FieldElement::new(11)),
+            (FieldElement::new(3), FieldElement::new(100)),
+        ];
+        let newton = Polynomial::newton_interpolate(&points);
+        let lagrange = Polynomial::lagrange_interpolate(&points);
+        assert_eq!(newton.coeffs, lagrange.coeffs);
+        for (x, y) in points {
+            assert_eq!(newton.evaluate(x), y);
+        }
+    }
+
+    #[test]
+    fn test_from_domain_values() {
+        let domain = EvaluationDomain::new(4).unwrap();
+        let values = vec![
+            FieldElement::new(3),
+            FieldElement::new(1),
+            FieldElement::new(4),
+            FieldElement::new(1),
+        ];
+        let p = Polynomial::from_domain_values(&values, &domain);
+        for (i, v) in values.into_iter().enumerate() {
+            assert_eq!(p.evaluate(domain.element(i)), v);
+        }
+    }
+
+    #[test]
+    fn test_shift_domain() {
+        // P(x) = 7 + 2x + 5x^2 + 9x^3
+        let p = Polynomial::new(vec![
+            FieldElement::new(7),
+            FieldElement::new(2),
+            FieldElement::new(5),
+            FieldElement::new(9),
+        ]);
+        let omega = FieldElement::new(10);
+        let shifted = p.shift_domain(omega.clone());
+        for x in [0, 1, 3, 42, 100] {
+            let x = FieldElement::new(x);
+            assert_eq!(shifted.evaluate(x.clone()), p.evaluate(omega.multiply(&x)));
+        }
+    }
+
+    #[test]
+    fn test_make_monic() {
+        // 2 + 4x -> inv(4)·2 + x
+        let p = Polynomial::new(vec![FieldElement::new(2), FieldElement::new(4)]);
+        let (lead, monic) = p.make_monic();
+        assert_eq!(lead, FieldElement::new(4));
+        assert_eq!(
+            monic.coeffs,
+            vec![
+                FieldElement::new(4)
+                    .inverse()
+                    .multiply(&FieldElement::new(2)),
+                FieldElement::one()
+            ]
+        );
+
+        let zero = Polynomial::new(vec![]);
+        let (lead, monic) = zero.make_monic();
+        assert_eq!(lead, FieldElement::zero());
+        assert_eq!(monic.coeffs, zero.coeffs);
+    }
+
+    #[test]
+    fn test_sparse_vanishing_polynomial() {
+        let n = 4;
+        let sparse = SparsePolynomial::vanishing_polynomial(n);
+        assert_eq!(
+            sparse.to_dense().coeffs,
+            Polynomial::vanishing_polynomial(n).coeffs
+        );
+
+        // 10 is a primitive 4th root of unity mod 101 (10^2 = 100 = -1)
+        let omega = FieldElement::new(10);
+        for i in 0..n as u64 {
+            assert_eq!(sparse.evaluate(omega.pow(i)), FieldElement::zero());
+        }
+        assert_ne!(sparse.evaluate(FieldElement::new(3)), FieldElement::zero());
+
+        // (x^4 - 1)(1 + 2x)
+        let p = Polynomial::new(vec![FieldElement::new(1), FieldElement::new(2)]);
+        assert_eq!(
+            sparse.mul(&p).coeffs,
+            (Polynomial::vanishing_polynomial(n) * p).coeffs
+        );
+    }
+
+    #[test]
+    fn test_zero_and_one() {
+        let p = Polynomial::new(vec![FieldElement::new(5), FieldElement::new(4)]);
+        assert_eq!(Polynomial::zero() + p.clone(), p);
+        assert_eq!(Polynomial::one() * p.clone(), p);
+
+        assert!(Polynomial::zero().is_zero());
+        assert!(Polynomial::new(vec![FieldElement::zero()]).is_zero());
+        assert_eq!(
+            Polynomial::zero(),
+            Polynomial::new(vec![FieldElement::zero()])
+        );
+        assert!(!Polynomial::zero().is_one());
+
+        assert!(Polynomial::one().is_one());
+        assert!(Polynomial::new(vec![FieldElement::one(), FieldElement::zero()]).is_one());
+        assert!(!Polynomial::one().is_zero());
+        assert!(!p.is_zero());
+        assert!(!p.is_one());
+    }
+
+    #[test]
+    fn test_equal_mod_vanishing() {
+        let n = 4;
+        let mut x_n = vec![FieldElement::zero(); n + 1];
+        x_n[n] = FieldElement::one();
+        let x_n = Polynomial::new(x_n);
+
+        assert!(x_n.equal_mod_vanishing(&Polynomial::one(), n));
+        assert!(!x_n.equal_mod_vanishing(&Polynomial::new(vec![FieldElement::new(2)]), n));
+
+        // The remainder matches long division by x^n - 1
+        let p = Polynomial::new((1..=9).map(FieldElement::new).collect());
+        let (_, r) = p.divide(&Polynomial::vanishing_polynomial(n));
+        assert_eq!(p.rem_vanishing(n), r);
+    }
+
+    #[test]
+    fn test_push_and_set_coeff() {
+        let expected = Polynomial::new(vec![
+            FieldElement::new(1),
+            FieldElement::new(2),
+            FieldElement::new(3),
+        ]);
+        let mut p = Polynomial::zero();
+        p.push_coeff(FieldElement::new(1));
+        p.push_coeff(FieldElement::new(2));
+        p.push_coeff(FieldElement::new(3));
+        assert_eq!(p, expected);
+        assert_eq!(p.degree(), 2);
+
+        // A zero low coefficient is kept, so 0 then 2 builds 2x
+        let mut two_x = Polynomial::zero();
+        two_x.push_coeff(FieldElement::zero());
+        two_x.push_coeff(FieldElement::new(2));
+        for x in [0, 1, 7, 100] {
+            let x = FieldElement::new(x);
+            assert_eq!(two_x.evaluate(x.clone()), x.multiply(&FieldElement::new(2)));
+        }
+
+        let mut q = Polynomial::zero();
+        q.set_coeff(2, FieldElement::new(3));
+        q.set_coeff(0, FieldElement::new(1));
+        q.set_coeff(1, FieldElement::new(2));
+        assert_eq!(q.coeffs, expected.coeffs);
+
+        // Clearing the leading coefficient trims it away
+        q.set_coeff(2, FieldElement::zero());
+        assert_eq!(q.degree(), 1);
+    }
+
+    #[test]
+    fn test_coeff_and_leading_coefficient() {
+        // 4 + 0x + 7x^2 with a trailing zero that gets trimmed
+        let p = Polynomial::new(vec![
+            FieldElement::new(4),
+            FieldElement::zero(),
+            FieldElement::new(7),
+            FieldElement::zero(),
+        ]);
+        assert_eq!(p.coeff(0), FieldElement::new(4));
+        assert_eq!(p.coeff(1), FieldElement::zero());
+        assert_eq!(p.coeff(2), FieldElement::new(7));
+        assert_eq!(p.coeff(3), FieldElement::zero());
+        assert_eq!(p.coeff(100), FieldElement::zero());
+        assert_eq!(p.leading_coefficient(), FieldElement::new(7));
+
+        assert_eq!(Polynomial::zero().coeff(0), FieldElement::zero());
+        assert_eq!(
+            Polynomial::zero().leading_coefficient(),
+            FieldElement::zero()
+        );
+    }
+
+    #[test]
+    fn test_evaluate_ext() {
+        // P(x) = 1 + 2x + 3x^2
+        let p = Polynomial::new(vec![
+            FieldElement::new(1),
+            FieldElement::new(2),
+            FieldElement::new(3),
+        ]);
+        for x in [0, 1, 5, 100] {
+            let x = FieldElement::new(x);
+            assert_eq!(
+                p.evaluate_ext(&FieldElementExt::from_base(x.clone())),
+                FieldElementExt::from_base(p.evaluate(x))
+            );
+        }
+        // P(u) = 1 + 2u + 3u^2 = 1 + 2u - 6 = -5 + 2u
+        let u = FieldElementExt::new(FieldElement::zero(), FieldElement::one());
+        assert_eq!(
+            p.evaluate_ext(&u),
+            FieldElementExt::new(FieldElement::new(96), FieldElement::new(2))
+        );
+
+        // With u^2 = -3 instead: P(u) = 1 + 2u - 9 = -8 + 2u, in the same extension
+        let nr = FieldElement::new(101 - 3);
+        let u =
+            FieldElementExt::with_nonresidue(FieldElement::zero(), FieldElement::one(), nr.clone());
+        assert_eq!(
+            p.evaluate_ext(&u),
+            FieldElementExt::with_nonresidue(
+                FieldElement::new(93),
+                FieldElement::new(2),
+                nr.clone()
+            )
+        );
+        // The zero polynomial still lands in u's extension
+        assert_eq!(Polynomial::zero().evaluate_ext(&u).nonresidue, nr);
+    }
+
+    #[test]
+    fn test_evaluations_round_trip() {
+        let domain = EvaluationDomain::new(5).unwrap();
+        for degree in 0..domain.size {
+            let p = Polynomial::new(
+                (0..=degree as u64)
+                    .map(|c| FieldElement::new(3 * c + 1))
+                    .collect(),
+            );
+            let evals = p.to_evaluations(&domain);
+            assert_eq!(evals.len(), domain.size);
+            assert_eq!(Polynomial::from_evaluations(&evals, &domain), p);
+        }
+
+        // x^5 wraps around to 1 over a domain of size 5
+        let mut x_5 = Polynomial::zero();
+        x_5.set_coeff(5, FieldElement::one());
+        assert_eq!(
+            Polynomial::from_evaluations(&x_5.to_evaluations(&domain), &domain),
+            Polynomial::one()
+        );
+    }
+
+    #[test]
+    fn test_div_exact() {
+        let x_minus_1 = Polynomial::new(vec![FieldElement::one().negate(), FieldElement::one()]);
+        let x_plus_1 = Polynomial::new(vec![FieldElement::one(), FieldElement::one()]);
+        // x^2 - 1 = (x - 1)(x + 1)
+        assert_eq!(
+            Polynomial::vanishing_polynomial(2).div_exact(&x_minus_1),
+            Some(x_plus_1)
+        );
+        // x^2 = (x - 1)(x + 1) + 1
+        let x_2 = Polynomial::new(vec![
+            FieldElement::zero(),
+            FieldElement::zero(),
+            FieldElement::one(),
+        ]);
+        assert_eq!(x_2.div_exact(&x_minus_1), None);
+    }
+
+    #[test]
+    fn test_is_scalar_multiple_of() {
+        // 2 + 4x = 2·(1 + 2x)
+        assert_eq!(
+            poly![2, 4].is_scalar_multiple_of(&poly![1, 2]),
+            Some(FieldElement::new(2))
+        );
+        // 1 + 2x = 51·(2 + 4x), 51 = 2⁻¹ mod 101
+        assert_eq!(
+            poly![1, 2].is_scalar_multiple_of(&poly![2, 4]),
+            Some(FieldElement::new(51))
+        );
+        assert_eq!(poly![1, 2].is_scalar_multiple_of(&poly![1, 3]), None);
+        assert_eq!(poly![1, 2].is_scalar_multiple_of(&poly![0, 0, 1]), None);
+
+        assert_eq!(
+            Polynomial::zero().is_scalar_multiple_of(&poly![1, 2]),
+            Some(FieldElement::zero())
+        );
+        assert_eq!(
+            Polynomial::zero().is_scalar_multiple_of(&Polynomial::zero()),
+            Some(FieldElement::zero())
+        );
+        assert_eq!(poly![1, 2].is_scalar_multiple_of(&Polynomial::zero()), None);
+    }
+
+    #[test]
+    fn test_truncate_and_split_at() {
+        // 1 + 2x + 3x^2
+        let p = Polynomial::new(vec![
+            FieldElement::new(1),
+            FieldElement::new(2),
+            FieldElement::new(3),
+        ]);
+        let low = Polynomial::new(vec![FieldElement::new(1), FieldElement::new(2)]);
+        assert_eq!(p.truncate(1), low);
+        assert_eq!(p.truncate(5), p);
+
+        let (lo, hi) = p.split_at(1);
+        assert_eq!(lo, low);
+        assert_eq!(hi, Polynomial::new(vec![FieldElement::new(3)]));
+        // p = lo + x^2·hi
+        let x_2 = Polynomial::new(vec![
+            FieldElement::zero(),
+            FieldElement::zero(),
+            FieldElement::one(),
+        ]);
+        assert_eq!(lo + x_2 * hi, p);
+
+        let (lo, hi) = p.split_at(4);
+        assert_eq!(lo, p);
+        assert!(hi.is_zero());
+    }
+
+    #[test]
+    fn test_mul_empty_operand() {
+        let p = Polynomial::new(vec![FieldElement::new(1), FieldElement::new(2)]);
+        assert!((Polynomial::new(vec![]) * p.clone()).is_zero());
+        assert!((p * Polynomial::new(vec![])).is_zero());
+        assert!((Polynomial::new(vec![]) * Polynomial::new(vec![])).is_zero());
+    }
+
+    #[test]
+    fn test_iter_terms() {
+        // 1 + 0x + 3x^2
+        let p = Polynomial::new(vec![
+            FieldElement::new(1),
+            FieldElement::zero(),
+            FieldElement::new(3),
+        ]);
+        let terms: Vec<(usize, &FieldElement)> = p.iter_terms().collect();
+        assert_eq!(terms.len(), 3);
+        assert_eq!(terms[1], (1, &FieldElement::zero()));
+
+        let nonzero: Vec<(usize, FieldElement)> = p
+            .iter_nonzero_terms()
+            .map(|(degree, c)| (degree, c.clone()))
+            .collect();
+        assert_eq!(
+            nonzero,
+            vec![(0, FieldElement::new(1)), (2, FieldElement::new(3))]
+        );
+    }
+
+    #[test]
+    fn test_mul_by_x_pow() {
+        let p = Polynomial::new(vec![FieldElement::new(1), FieldElement::new(2)]); // 1 + 2x
+        let expected = Polynomial::new(vec![
+            FieldElement::zero(),
+            FieldElement::zero(),
+            FieldElement::new(1),
+            FieldElement::new(2),
+        ]); // x^2 + 2x^3
+        assert_eq!(p.mul_by_x_pow(2), expected);
+        assert_eq!(p.mul_by_x_pow(0), p);
+        assert!(Polynomial::zero().mul_by_x_pow(3).is_zero());
+    }
+
+    #[test]
+    fn test_split_into() {
+        // t(x) = 1 + 2x + ... + 10x^9 split in 3 chunks of 4 coefficients
+        let t = Polynomial::new((1..=10).map(FieldElement::new).collect());
+        let chunks = t.split_into(3, 4);
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(
+            chunks[2],
+            Polynomial::new(vec![FieldElement::new(9), FieldElement::new(10)])
+        );
+
+        let mut recombined = Polynomial::zero();
+        for (i, chunk) in chunks.into_iter().enumerate() {
+            recombined += chunk.mul_by_x_pow(i * 4);
+        }
+        assert_eq!(recombined, t);
+    }
+
+    #[test]
+    #[should_panic(expected = "Polynomial doesn't fit")]
+    fn test_split_into_too_small() {
+        let t = Polynomial::new((1..=10).map(FieldElement::new).collect());
+        t.split_into(2, 4);
+    }
+
     #[test]
     fn test_lagrange_interpolate() {
         let points = vec![
@@ -325,4 +1218,116 @@ mod tests {
             assert_eq!(p.evaluate(x.clone()), y);
         }
     }
+
+    #[test]
+    #[should_panic(expected = "Interpolation points must have distinct x-coordinates")]
+    fn test_lagrange_interpolate_duplicate_x() {
+        let points = vec![
+            (FieldElement::new(3), FieldElement::new(1)),
+            (FieldElement::new(5), FieldElement::new(2)),
+            (FieldElement::new(104), FieldElement::new(4)), // 104 = 3 mod 101
+        ];
+        Polynomial::lagrange_interpolate(&points);
+    }
+
+    #[test]
+    fn test_lagrange_precompute() {
+        use ark_std::rand::{rngs::StdRng, SeedableRng};
+        let mut rng = StdRng::seed_from_u64(1914);
+        let xs: Vec<FieldElement> = [2, 5, 7, 11, 100].map(FieldElement::new).to_vec();
+        let precompute = LagrangePrecompute::new(&xs);
+        // L_i is 1 at x_i and 0 at every other x-coordinate
+        for (i, basis) in precompute.basis.iter().enumerate() {
+            for (j, xj) in xs.iter().enumerate() {
+                let expected = if i == j {
+                    FieldElement::one()
+                } else {
+                    FieldElement::zero()
+                };
+                assert_eq!(basis.evaluate(xj.clone()), expected);
+            }
+        }
+        for _ in 0..10 {
+            let ys: Vec<FieldElement> = xs.iter().map(|_| FieldElement::rand(&mut rng)).collect();
+            let points: Vec<(FieldElement, FieldElement)> =
+                xs.iter().cloned().zip(ys.iter().cloned()).collect();
+            assert_eq!(
+                precompute.interpolate(&ys),
+                Polynomial::lagrange_interpolate(&points)
+            );
+        }
+        assert!(precompute
+            .interpolate(&vec![FieldElement::zero(); xs.len()])
+            .is_zero());
+    }
+
+    #[test]
+    #[should_panic(expected = "Interpolation points must have distinct x-coordinates")]
+    fn test_lagrange_precompute_duplicate_x() {
+        LagrangePrecompute::new(&[3, 5, 104].map(FieldElement::new));
+    }
+
+    #[test]
+    fn test_polynomial_sum_and_product() {
+        // 3 + 5x^2 + 7x^3 as a sum of monomials
+        let monomials = vec![
+            Polynomial::new(vec![FieldElement::new(3)]),
+            Polynomial::new(vec![
+                FieldElement::zero(),
+                FieldElement::zero(),
+                FieldElement::new(5),
+            ]),
+            Polynomial::new(vec![FieldElement::new(7)]).mul_by_x_pow(3),
+        ];
+        let sum: Polynomial = monomials.into_iter().sum();
+        assert_eq!(
+            sum.coeffs,
+            vec![
+                FieldElement::new(3),
+                FieldElement::zero(),
+                FieldElement::new(5),
+                FieldElement::new(7)
+            ]
+        );
+
+        // (x - 1)(x - 2)(x - 3) = x^3 - 6x^2 + 11x - 6
+        let product: Polynomial = (1..=3)
+            .map(|r| Polynomial::new(vec![FieldElement::new(r).negate(), FieldElement::one()]))
+            .product();
+        assert_eq!(
+            product.coeffs,
+            vec![
+                FieldElement::new(101 - 6),
+                FieldElement::new(11),
+                FieldElement::new(101 - 6),
+                FieldElement::one()
+            ]
+        );
+
+        assert_eq!(
+            Vec::<Polynomial>::new().into_iter().sum::<Polynomial>(),
+            Polynomial::zero()
+        );
+        assert!(Vec::<Polynomial>::new()
+            .into_iter()
+            .product::<Polynomial>()
+            .is_one());
+    }
+
+    #[test]
+    fn test_poly_macro() {
+        assert_eq!(
+            poly![1, 2, 3],
+            Polynomial::new(vec![
+                FieldElement::new(1),
+                FieldElement::new(2),
+                FieldElement::new(3),
+            ])
+        );
+        // Reduced and trimmed like `new`
+        assert_eq!(poly![102, 0, 0,], poly![1]);
+        assert_eq!(poly![], Polynomial::zero());
+        let x = 4;
+        assert_eq!(poly![x * 25, 1].coeffs[0], FieldElement::new(100));
+    }
 }
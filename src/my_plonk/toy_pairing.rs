@@ -1,4 +1,5 @@
-use super::field::FieldElement;
+use super::elliptic_curve::{EllipticCurve, Point, PointExt};
+use super::field::{FieldElement, FieldElementExt, Scalar};
 
 /*
     Disclaimer: This is obviously a toy pairing implementation.
@@ -6,20 +7,117 @@ use super::field::FieldElement;
     will take me to understand them to then implement some simple
     version, I decided to keep this as simple as possible.
 
-    The check still works, but it lacks of key security features of
-    real pairings (affecting soundness):
-    - Outputs a F_101 element ignoring the embedding degree (k = 2)
+    `Pairing::new` is the original toy map. The check still works, but it
+    lacks of key security features of real pairings (affecting soundness):
+    - Ignores the embedding degree (k = 2), the output stays in F_101
     - Lacks bilinearity ( e([a]P, b[Q]) != e(P, Q)^ab )
+
+    `Pairing::from_points` is an intermediate teaching step: bilinear by
+    construction because it solves the discrete logs of both inputs, which
+    is only possible because the groups have 17 elements.
+
+    `Pairing::tate` is a proper (reduced) Tate pairing: a Miller loop over
+    the order-17 subgroup followed by the final exponentiation to the
+    (101^2 - 1)/17 power. Its output lives in the order-17 subgroup of F_101^2.
 */
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct Pairing {
-    pub e: FieldElement,
+    pub e: FieldElementExt,
 }
 
 impl Pairing {
+    /// Exponent of the final exponentiation: (p^k - 1) / r
+    pub const FINAL_EXPONENT: u64 =
+        (FieldElement::MODULUS * FieldElement::MODULUS - 1) / Scalar::MODULUS;
+
+    #[allow(dead_code)]
     pub fn new(a: FieldElement, b: FieldElement) -> Self {
-        Pairing { e: a.multiply(&b) }
+        Pairing {
+            e: FieldElementExt::from_base(a.multiply(&b)),
+        }
+    }
+
+    /// e(P, Q) = g_t^(a·b) where P = [a]G1, Q = [b]G2 and g_t = e(G1, G2).
+    /// The discrete logs are found by trying every scalar, which only works
+    /// because the subgroup is tiny. Panics if P or Q is outside the subgroups.
+    #[allow(dead_code)]
+    pub fn from_points(p: &Point, q: &PointExt, curve: &EllipticCurve) -> Self {
+        let g1 = curve.generator_g1();
+        let g2 = curve.generator_g2();
+        let a = curve
+            .discrete_log(&g1, p)
+            .expect("P is not in the subgroup generated by G1");
+        let b = (0..curve.order())
+            .find(|b| &g2.scalar_mul(curve, Scalar::new(*b)) == q)
+            .expect("Q is not in the subgroup generated by G2");
+        let g_t = Self::tate(&g1, &g2, curve);
+        g_t.pow(a * b)
+    }
+
+    /// Reduced Tate pairing e(P, Q) = f_{r,P}(Q)^((p^2 - 1) / r)
+    #[allow(dead_code)]
+    pub fn tate(p: &Point, q: &PointExt, curve: &EllipticCurve) -> Self {
+        Pairing {
+            e: Self::final_exponentiate(Self::miller_loop(p, q, curve)),
+        }
+    }
+
+    /// Raises the raw Miller loop output to (101^2 - 1)/17 so that the
+    /// result is the canonical representative in the order-17 subgroup.
+    #[allow(dead_code)]
+    pub fn final_exponentiate(raw: FieldElementExt) -> FieldElementExt {
+        raw.pow(Self::FINAL_EXPONENT)
+    }
+
+    /// Computes f_{r,P}(Q) with r the subgroup order. The vertical lines in the
+    /// denominators have their x-coordinate in F_101 (Q = (x, yu)), so they are
+    /// wiped out by the final exponentiation and can be skipped.
+    fn miller_loop(p: &Point, q: &PointExt, curve: &EllipticCurve) -> FieldElementExt {
+        if p.is_infinity || q.is_infinity {
+            return FieldElementExt::one();
+        }
+        let r = curve.order();
+        let mut f = FieldElementExt::one();
+        let mut t = p.clone();
+        for n in (0..(63 - r.leading_zeros())).rev() {
+            f = f.multiply(&f).multiply(&Self::line(&t, &t, q, curve));
+            t = curve.add(&t, &t);
+            if (r >> n) & 1 == 1 {
+                f = f.multiply(&Self::line(&t, p, q, curve));
+                t = curve.add(&t, p);
+            }
+        }
+        f
+    }
+
+    /// Evaluates at Q the line through A and B (the tangent when A == B)
+    fn line(a: &Point, b: &Point, q: &PointExt, curve: &EllipticCurve) -> FieldElementExt {
+        let x_a = FieldElementExt::from_base(a.x.clone());
+        let y_a = FieldElementExt::from_base(a.y.clone());
+        if (a.x == b.x && a.y != b.y) || (a == b && a.y == FieldElement::zero()) {
+            // Vertical line: x - x_A
+            return q.x.substract(&x_a);
+        }
+        let m = if a == b {
+            let num =
+                a.x.multiply(&a.x)
+                    .multiply(&FieldElement::new(3))
+                    .add(&curve.a());
+            num.divide(&a.y.multiply(&FieldElement::new(2)))
+        } else {
+            b.y.substract(&a.y).divide(&b.x.substract(&a.x))
+        };
+        // y - y_A - m(x - x_A)
+        q.y.substract(&y_a)
+            .substract(&q.x.substract(&x_a).scalar_mul(m))
+    }
+
+    /// True for the identity of the target group, e.g. to check a product
+    /// of pairings e(A, B)·e(C, D) == 1
+    #[allow(dead_code)]
+    pub fn is_one(&self) -> bool {
+        self.e == FieldElementExt::one()
     }
 
     #[allow(dead_code)]
@@ -32,7 +130,7 @@ impl Pairing {
     #[allow(dead_code)]
     pub fn pow(&self, n: u64) -> Self {
         let mut result = Pairing {
-            e: FieldElement::new(1),
+            e: FieldElementExt::one(),
         };
         let mut base = self.clone();
         let mut exp = n;
@@ -46,3 +144,116 @@ impl Pairing {
         result
     }
 }
+
+/// Accumulates pairing terms e(A_i, B_i) and checks in one final step that
+/// their product is the identity: ∏ e(A_i, B_i) == 1
+#[derive(Clone, Debug)]
+pub struct PairingCheck {
+    curve: EllipticCurve,
+    product: Pairing,
+}
+
+impl PairingCheck {
+    #[allow(dead_code)]
+    pub fn new(curve: &EllipticCurve) -> Self {
+        PairingCheck {
+            curve: curve.clone(),
+            product: Pairing {
+                e: FieldElementExt::one(),
+            },
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn add_term(&mut self, a: Point, b: PointExt) {
+        let term = Pairing::tate(&a, &b, &self.curve);
+        self.product = self.product.mul(&term);
+    }
+
+    #[allow(dead_code)]
+    pub fn verify(&self) -> bool {
+        self.product.is_one()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tate_pairing_has_order_17() {
+        let curve = EllipticCurve::new();
+        let e = Pairing::tate(&curve.generator_g1(), &curve.generator_g2(), &curve);
+        assert_ne!(e.e, FieldElementExt::one());
+        assert_eq!(e.pow(Scalar::MODULUS).e, FieldElementExt::one());
+    }
+
+    #[test]
+    fn test_tate_pairing_bilinear_in_g1() {
+        let curve = EllipticCurve::new();
+        let g1 = curve.generator_g1();
+        let g2 = curve.generator_g2();
+        let e = Pairing::tate(&g1, &g2, &curve);
+        for a in 0..Scalar::MODULUS {
+            let a_g1 = g1.scalar_mul(&curve, Scalar::new(a));
+            assert_eq!(Pairing::tate(&a_g1, &g2, &curve), e.pow(a));
+        }
+    }
+
+    #[test]
+    fn test_from_points_is_bilinear() {
+        let curve = EllipticCurve::new();
+        let g1 = curve.generator_g1();
+        let g2 = curve.generator_g2();
+        let e = Pairing::from_points(&g1, &g2, &curve);
+        for a in 0..Scalar::MODULUS {
+            for b in 0..Scalar::MODULUS {
+                let a_g1 = g1.scalar_mul(&curve, Scalar::new(a));
+                let b_g2 = g2.scalar_mul(&curve, Scalar::new(b));
+                assert_eq!(Pairing::from_points(&a_g1, &b_g2, &curve), e.pow(a * b));
+            }
+        }
+        assert_eq!(e, Pairing::tate(&g1, &g2, &curve));
+    }
+
+    #[test]
+    fn test_pairing_eq_and_is_one() {
+        let curve = EllipticCurve::new();
+        let g1 = curve.generator_g1();
+        let g2 = curve.generator_g2();
+        let e = Pairing::tate(&g1, &g2, &curve);
+        assert!(e.eq(&Pairing::tate(&g1, &g2, &curve)));
+        assert!(!e.eq(&e.pow(2)));
+
+        assert!(!e.is_one());
+        assert!(e.pow(Scalar::MODULUS).is_one());
+        assert!(Pairing::tate(&curve.infinity(), &g2, &curve).is_one());
+        // e([3]G1, G2)·e([14]G1, G2) = e([17]G1, G2) = 1
+        let e_3 = Pairing::tate(&g1.scalar_mul(&curve, Scalar::new(3)), &g2, &curve);
+        let e_14 = Pairing::tate(&g1.scalar_mul(&curve, Scalar::new(14)), &g2, &curve);
+        assert!(e_3.mul(&e_14).is_one());
+    }
+
+    #[test]
+    fn test_pairing_check() {
+        let curve = EllipticCurve::new();
+        let g1 = curve.generator_g1();
+        let g2 = curve.generator_g2();
+        let empty = PairingCheck::new(&curve);
+        assert!(empty.verify());
+
+        // e([2]G1, [3]G2)·e([5]G1, G2)·e([6]G1, G2) = g_t^(6 + 5 + 6) = 1,
+        // replacing the last term with e([11]G1, G2) gives g_t^22 != 1
+        let mut balanced = PairingCheck::new(&curve);
+        balanced.add_term(
+            g1.scalar_mul(&curve, Scalar::new(2)),
+            g2.scalar_mul(&curve, Scalar::new(3)),
+        );
+        balanced.add_term(g1.scalar_mul(&curve, Scalar::new(5)), g2.clone());
+        let mut unbalanced = balanced.clone();
+        balanced.add_term(g1.scalar_mul(&curve, Scalar::new(6)), g2.clone());
+        unbalanced.add_term(g1.scalar_mul(&curve, Scalar::new(11)), g2);
+        assert!(balanced.verify());
+        assert!(!unbalanced.verify());
+    }
+}